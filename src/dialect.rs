@@ -0,0 +1,37 @@
+/// SQL dialects the parser knows about
+/// The parser accepts standard SQL by default (`Generic`). Syntax that only some databases
+/// understand is enabled by choosing the matching dialect when creating the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dialect {
+    #[default]
+    Generic,
+    MySql,
+    Postgres,
+    BigQuery,
+    DuckDb,
+}
+
+impl Dialect {
+    /// Human-readable name used in error messages, e.g. "MySQL"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dialect::Generic => "generic",
+            Dialect::MySql => "MySQL",
+            Dialect::Postgres => "PostgreSQL",
+            Dialect::BigQuery => "BigQuery",
+            Dialect::DuckDb => "DuckDB",
+        }
+    }
+
+    /// Whether a `*` projection may be followed by `EXCEPT (...)` to leave out columns and
+    /// `REPLACE (... AS ...)` to substitute expressions for columns
+    pub fn supports_wildcard_modifiers(&self) -> bool {
+        matches!(self, Dialect::BigQuery | Dialect::DuckDb)
+    }
+
+    /// Whether `LIMIT` and `OFFSET` take any expression, like `LIMIT (SELECT count(*) FROM t)`,
+    /// rather than only a number
+    pub fn supports_limit_expressions(&self) -> bool {
+        matches!(self, Dialect::Postgres)
+    }
+}
@@ -0,0 +1,401 @@
+/// GraphViz exporter for parsed statements and expressions
+/// This module turns an AST into a DOT graph description, which is handy when debugging
+/// deeply nested expression trees (render it with `dot -Tpng`).
+use crate::statement::{AlterAction, Constraint, Expression, InsertSource, Statement, TableColumn, WhereClause};
+
+/// Accumulates DOT node and edge lines while handing out unique node identifiers
+struct DotWriter {
+    lines: Vec<String>,
+    next_id: usize,
+}
+
+impl DotWriter {
+    fn new() -> Self {
+        DotWriter {
+            lines: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a node with the given label and returns its identifier
+    fn node(&mut self, label: &str) -> String {
+        let id = format!("n{}", self.next_id);
+        self.next_id += 1;
+        self.lines.push(format!("    {} [label=\"{}\"];", id, escape_label(label)));
+        id
+    }
+
+    fn edge(&mut self, from: &str, to: &str) {
+        self.lines.push(format!("    {} -> {};", from, to));
+    }
+
+    fn finish(self, graph_name: &str) -> String {
+        let mut dot = format!("digraph {} {{\n", graph_name);
+        for line in self.lines {
+            dot.push_str(&line);
+            dot.push('\n');
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+/// Escapes characters that have a special meaning inside a quoted DOT label
+fn escape_label(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Expression {
+    /// Renders the expression tree as a GraphViz DOT graph
+    ///
+    /// Every operator becomes a node whose children are its operands, e.g. `a + b * c` yields a
+    /// `+` root with edges to `a` and to a `*` node.
+    pub fn to_dot(&self) -> String {
+        let mut writer = DotWriter::new();
+        self.write_dot(&mut writer);
+        writer.finish("Expression")
+    }
+
+    fn write_dot(&self, writer: &mut DotWriter) -> String {
+        match self {
+            Expression::BinaryOperation { left_operand, operator, right_operand } => {
+                let id = writer.node(&operator.to_string());
+                let left = left_operand.write_dot(writer);
+                writer.edge(&id, &left);
+                let right = right_operand.write_dot(writer);
+                writer.edge(&id, &right);
+                id
+            }
+            Expression::UnaryOperation { operand, operator } => {
+                let id = writer.node(&operator.to_string());
+                let child = operand.write_dot(writer);
+                writer.edge(&id, &child);
+                id
+            }
+            Expression::Like { expr, pattern, negated } => {
+                let id = writer.node(if *negated { "NOT LIKE" } else { "LIKE" });
+                let left = expr.write_dot(writer);
+                writer.edge(&id, &left);
+                let right = pattern.write_dot(writer);
+                writer.edge(&id, &right);
+                id
+            }
+            Expression::IsNull { expr, negated } => {
+                let id = writer.node(if *negated { "IS NOT NULL" } else { "IS NULL" });
+                let child = expr.write_dot(writer);
+                writer.edge(&id, &child);
+                id
+            }
+            Expression::IsDistinctFrom { left, right, negated } => {
+                let id = writer.node(if *negated { "IS NOT DISTINCT FROM" } else { "IS DISTINCT FROM" });
+                let left = left.write_dot(writer);
+                writer.edge(&id, &left);
+                let right = right.write_dot(writer);
+                writer.edge(&id, &right);
+                id
+            }
+            Expression::Between { expr, low, high, negated } => {
+                let id = writer.node(if *negated { "NOT BETWEEN" } else { "BETWEEN" });
+                for operand in [expr, low, high] {
+                    let child = operand.write_dot(writer);
+                    writer.edge(&id, &child);
+                }
+                id
+            }
+            Expression::InList { expr, list, negated } => {
+                let id = writer.node(if *negated { "NOT IN" } else { "IN" });
+                let left = expr.write_dot(writer);
+                writer.edge(&id, &left);
+                for item in list {
+                    let child = item.write_dot(writer);
+                    writer.edge(&id, &child);
+                }
+                id
+            }
+            Expression::FunctionCall { name, distinct, args } => {
+                let label = if *distinct { format!("{}(DISTINCT)", name) } else { format!("{}()", name) };
+                let id = writer.node(&label);
+                for arg in args {
+                    let child = arg.write_dot(writer);
+                    writer.edge(&id, &child);
+                }
+                id
+            }
+            Expression::WindowFunction { function, window } => {
+                let id = writer.node(&format!("OVER {}", window));
+                let child = function.write_dot(writer);
+                writer.edge(&id, &child);
+                id
+            }
+            Expression::Case { operand, when_clauses, else_clause } => {
+                let id = writer.node("CASE");
+                if let Some(operand) = operand {
+                    let child = operand.write_dot(writer);
+                    writer.edge(&id, &child);
+                }
+                for (condition, result) in when_clauses {
+                    let when = writer.node("WHEN");
+                    writer.edge(&id, &when);
+                    let child = condition.write_dot(writer);
+                    writer.edge(&when, &child);
+                    let then = writer.node("THEN");
+                    writer.edge(&when, &then);
+                    let child = result.write_dot(writer);
+                    writer.edge(&then, &child);
+                }
+                if let Some(else_clause) = else_clause {
+                    let otherwise = writer.node("ELSE");
+                    writer.edge(&id, &otherwise);
+                    let child = else_clause.write_dot(writer);
+                    writer.edge(&otherwise, &child);
+                }
+                id
+            }
+            Expression::InSubquery { expr, subquery, negated } => {
+                let id = writer.node(if *negated { "NOT IN" } else { "IN" });
+                let left = expr.write_dot(writer);
+                writer.edge(&id, &left);
+                let child = subquery.write_dot(writer);
+                writer.edge(&id, &child);
+                id
+            }
+            Expression::Subquery(subquery) => subquery.write_dot(writer),
+            Expression::Exists { subquery, negated } => {
+                let id = writer.node(if *negated { "NOT EXISTS" } else { "EXISTS" });
+                let child = subquery.write_dot(writer);
+                writer.edge(&id, &child);
+                id
+            }
+            Expression::Aliased { expr, alias } => {
+                let id = writer.node(&format!("AS {}", alias));
+                let child = expr.write_dot(writer);
+                writer.edge(&id, &child);
+                id
+            }
+            leaf => writer.node(&leaf.to_string()),
+        }
+    }
+}
+
+impl Statement {
+    /// Renders the statement as a GraphViz DOT graph
+    ///
+    /// The statement is the root node, each clause (columns, FROM, WHERE, ...) is a child node
+    /// and the expressions of a clause hang below it as in `Expression::to_dot`.
+    pub fn to_dot(&self) -> String {
+        let mut writer = DotWriter::new();
+        self.write_dot(&mut writer);
+        writer.finish("Statement")
+    }
+
+    fn write_dot(&self, writer: &mut DotWriter) -> String {
+        match self {
+            Statement::Select { distinct, columns, wildcard_except, wildcard_replace, from, joins, r#where, groupby, having, windows, orderby, limit, offset } => {
+                let root = writer.node(if *distinct { "SELECT DISTINCT" } else { "SELECT" });
+                write_expression_list(writer, &root, "columns", columns);
+                if !wildcard_except.is_empty() {
+                    let except = writer.node(&format!("EXCEPT ({})", wildcard_except.join(", ")));
+                    writer.edge(&root, &except);
+                }
+                if !wildcard_replace.is_empty() {
+                    let replace = writer.node("REPLACE");
+                    writer.edge(&root, &replace);
+                    for (expr, column) in wildcard_replace {
+                        let replacement = writer.node(&format!("AS {}", column));
+                        writer.edge(&replace, &replacement);
+                        let child = expr.write_dot(writer);
+                        writer.edge(&replacement, &child);
+                    }
+                }
+                let from_id = writer.node(&format!("FROM {}", from));
+                writer.edge(&root, &from_id);
+                for join in joins {
+                    let join_id = writer.node(&format!("{} {}", join.join_type, join.table));
+                    writer.edge(&root, &join_id);
+                    write_expression_list(writer, &join_id, "ON", std::slice::from_ref(&join.on));
+                }
+                if let Some(expr) = r#where {
+                    write_expression_list(writer, &root, "WHERE", std::slice::from_ref(expr));
+                }
+                if !groupby.is_empty() {
+                    write_expression_list(writer, &root, "GROUP BY", groupby);
+                }
+                if let Some(expr) = having {
+                    write_expression_list(writer, &root, "HAVING", std::slice::from_ref(expr));
+                }
+                if !windows.is_empty() {
+                    let clause = writer.node("WINDOW");
+                    writer.edge(&root, &clause);
+                    for (name, spec) in windows {
+                        let window = writer.node(&format!("{} AS {}", name, spec));
+                        writer.edge(&clause, &window);
+                    }
+                }
+                if !orderby.is_empty() {
+                    let clause = writer.node("ORDER BY");
+                    writer.edge(&root, &clause);
+                    for item in orderby {
+                        let mut child = item.expr.write_dot(writer);
+                        if let Some(direction) = &item.direction {
+                            let direction_id = writer.node(&direction.to_string());
+                            writer.edge(&direction_id, &child);
+                            child = direction_id;
+                        }
+                        writer.edge(&clause, &child);
+                    }
+                }
+                if let Some(expr) = limit {
+                    write_expression_list(writer, &root, "LIMIT", std::slice::from_ref(expr));
+                }
+                if let Some(expr) = offset {
+                    write_expression_list(writer, &root, "OFFSET", std::slice::from_ref(expr));
+                }
+                root
+            }
+            Statement::CreateTable { table_name, temporary, column_list, table_constraints, options } => {
+                let label = if *temporary { "CREATE TEMPORARY TABLE" } else { "CREATE TABLE" };
+                let root = writer.node(&format!("{} {}", label, table_name));
+                for column in column_list {
+                    write_column(writer, &root, column);
+                }
+                for constraint in table_constraints {
+                    let child = writer.node(&constraint.to_string());
+                    writer.edge(&root, &child);
+                }
+                for (name, value) in options {
+                    let option = writer.node(&format!("{}={}", name, value));
+                    writer.edge(&root, &option);
+                }
+                root
+            }
+            Statement::Update { table_name, assignments, r#where } => {
+                let root = writer.node(&format!("UPDATE {}", table_name));
+                write_assignments(writer, &root, assignments);
+                match r#where {
+                    Some(WhereClause::Expression(expr)) => {
+                        write_expression_list(writer, &root, "WHERE", std::slice::from_ref(expr));
+                    }
+                    Some(WhereClause::CurrentOf(cursor)) => {
+                        let clause = writer.node(&format!("WHERE CURRENT OF {}", cursor));
+                        writer.edge(&root, &clause);
+                    }
+                    None => {}
+                }
+                root
+            }
+            Statement::DropTable { table_names, if_exists, drop_behavior } => {
+                let label = if *if_exists { "DROP TABLE IF EXISTS" } else { "DROP TABLE" };
+                let root = writer.node(&format!("{} {}", label, table_names.join(", ")));
+                if let Some(behavior) = drop_behavior {
+                    let child = writer.node(&behavior.to_string());
+                    writer.edge(&root, &child);
+                }
+                root
+            }
+            Statement::Fetch { direction, cursor } | Statement::Move { direction, cursor } => {
+                let keyword = if matches!(self, Statement::Fetch { .. }) { "FETCH" } else { "MOVE" };
+                let root = writer.node(&format!("{} {}", keyword, cursor));
+                if let Some(direction) = direction {
+                    let child = writer.node(&direction.to_string());
+                    writer.edge(&root, &child);
+                }
+                root
+            }
+            Statement::Insert { table_name, columns, source, returning } => {
+                let root = writer.node(&format!("INSERT INTO {}", table_name));
+                if !columns.is_empty() {
+                    let column_list = writer.node(&format!("({})", columns.join(", ")));
+                    writer.edge(&root, &column_list);
+                }
+                match source {
+                    InsertSource::Values(rows) => {
+                        for row in rows {
+                            write_expression_list(writer, &root, "VALUES", row);
+                        }
+                    }
+                    InsertSource::Assignments(assignments) => write_assignments(writer, &root, assignments),
+                }
+                if !returning.is_empty() {
+                    write_expression_list(writer, &root, "RETURNING", returning);
+                }
+                root
+            }
+            Statement::AlterTable { table_name, action } => {
+                let root = writer.node(&format!("ALTER TABLE {}", table_name));
+                match action {
+                    AlterAction::AddColumn(column) => {
+                        let add = writer.node("ADD COLUMN");
+                        writer.edge(&root, &add);
+                        write_column(writer, &add, column);
+                    }
+                    AlterAction::AddConstraint(constraint) => {
+                        let add = writer.node(&format!("ADD {}", constraint));
+                        writer.edge(&root, &add);
+                    }
+                    AlterAction::DropConstraint(name) => {
+                        let drop = writer.node(&format!("DROP CONSTRAINT {}", name));
+                        writer.edge(&root, &drop);
+                    }
+                    AlterAction::DropPrimaryKey => {
+                        let drop = writer.node("DROP PRIMARY KEY");
+                        writer.edge(&root, &drop);
+                    }
+                }
+                root
+            }
+            Statement::SetOperation { left, op, all, right } => {
+                let label = if *all { format!("{} ALL", op) } else { op.to_string() };
+                let root = writer.node(&label);
+                let left = left.write_dot(writer);
+                writer.edge(&root, &left);
+                let right = right.write_dot(writer);
+                writer.edge(&root, &right);
+                root
+            }
+        }
+    }
+}
+
+fn write_expression_list(writer: &mut DotWriter, parent: &str, label: &str, expressions: &[Expression]) {
+    let clause = writer.node(label);
+    writer.edge(parent, &clause);
+    for expr in expressions {
+        let child = expr.write_dot(writer);
+        writer.edge(&clause, &child);
+    }
+}
+
+/// Adds a SET node below `parent` with one `column =` node per assignment
+fn write_assignments(writer: &mut DotWriter, parent: &str, assignments: &[(String, Expression)]) {
+    let set = writer.node("SET");
+    writer.edge(parent, &set);
+    for (column, value) in assignments {
+        let assignment = writer.node(&format!("{} =", column));
+        writer.edge(&set, &assignment);
+        let child = value.write_dot(writer);
+        writer.edge(&assignment, &child);
+    }
+}
+
+fn write_column(writer: &mut DotWriter, parent: &str, column: &TableColumn) {
+    let id = writer.node(&format!("{} {:?}", column.column_name, column.column_type));
+    writer.edge(parent, &id);
+    for constraint in &column.constraints {
+        let constraint_id = match constraint {
+            Constraint::Check(expr) => {
+                let check = writer.node("CHECK");
+                let child = expr.write_dot(writer);
+                writer.edge(&check, &child);
+                check
+            }
+            Constraint::Default(expr) => {
+                let default = writer.node("DEFAULT");
+                let child = expr.write_dot(writer);
+                writer.edge(&default, &child);
+                default
+            }
+            other => writer.node(&format!("{:?}", other)),
+        };
+        writer.edge(&id, &constraint_id);
+    }
+}
@@ -0,0 +1,65 @@
+/// Error module for the parser
+/// Parse failures are reported as a `ParseError`, so callers can tell running out of input apart
+/// from a wrong token or from input the tokenizer could not read at all.
+use crate::token::Token;
+use crate::tokenizer::TokenizeError;
+use std::fmt::{self, Display, Formatter};
+
+/// Error produced when a statement or expression cannot be parsed
+/// `UnexpectedEof` carries where the input ended (like `after AS`), when the parser knows it.
+/// `Invalid` covers input that is well-formed token by token but still not accepted, such as
+/// `HAVING` without `GROUP BY` or syntax the chosen dialect does not support.
+/// `UnbalancedParentheses` replaces the error of a statement whose parentheses do not pair up, and
+/// carries the byte position of the parenthesis left without a partner.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    UnexpectedToken { expected: String, found: Token },
+    UnexpectedEof { context: Option<String> },
+    LexError(TokenizeError),
+    Invalid(String),
+    UnbalancedParentheses { position: usize },
+}
+
+impl ParseError {
+    pub(crate) fn unexpected(expected: impl Into<String>, found: &Token) -> Self {
+        ParseError::UnexpectedToken {
+            expected: expected.into(),
+            found: found.clone(),
+        }
+    }
+
+    pub(crate) fn eof(context: impl Into<String>) -> Self {
+        ParseError::UnexpectedEof {
+            context: Some(context.into()),
+        }
+    }
+
+    /// Replaces what an `UnexpectedToken` error expected with a more specific description
+    /// Used where a generic `expect_keyword` failure reads better in terms of the surrounding syntax.
+    pub(crate) fn expecting(self, expected: impl Into<String>) -> Self {
+        match self {
+            ParseError::UnexpectedToken { found, .. } => ParseError::UnexpectedToken {
+                expected: expected.into(),
+                found,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { expected, found } => write!(f, "Expected {}, got {:?}", expected, found),
+            ParseError::UnexpectedEof { context: Some(context) } => write!(f, "Unexpected end of input {}", context),
+            ParseError::UnexpectedEof { context: None } => write!(f, "Unexpected end of input"),
+            ParseError::LexError(error) => write!(f, "{}", error),
+            ParseError::Invalid(message) => write!(f, "{}", message),
+            ParseError::UnbalancedParentheses { position } => {
+                write!(f, "Unbalanced parentheses: unmatched parenthesis at position {}", position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
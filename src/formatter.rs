@@ -0,0 +1,695 @@
+/// Formatter module for rendering parsed SQL back to text
+/// This module is the single place that decides how AST nodes are printed, so the `Display`
+/// implementations and any caller that needs non-default output share the same code path.
+use crate::dialect::Dialect;
+use crate::statement::{
+    AlterAction, BinaryOperator, Constraint, DBType, Expression, FetchDirection, FrameBound, InsertSource, MatchType, OrderByItem, ReferentialAction, Statement, TableColumn, TableConstraint, TableRef, UnaryOperator, WhereClause,
+    Window, WindowSpec,
+};
+use crate::tokenizer::keyword;
+use std::borrow::Cow;
+
+/// Options controlling how the formatter renders the AST
+/// Every option is off by default, which gives the plain output used by `Display`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormatOptions {
+    /// Groups the digits of numeric literals in threes with `_`, e.g. `1000000` becomes `1_000_000`.
+    /// The underscore form is accepted by several SQL dialects, but not by this crate's tokenizer,
+    /// so grouped output is meant for display rather than reparsing.
+    pub group_digits: bool,
+    /// Dialect the output is meant for. Only operators whose spelling differs between dialects
+    /// consult it: string concatenation is `a || b` in standard SQL, but MySQL reads `||` as a
+    /// logical OR, so for MySQL it is written as `a OR b`.
+    pub dialect: Dialect,
+    /// Leaves out the spaces around symbolic binary operators, e.g. `a + b` becomes `a+b`.
+    /// `AND` and `OR` keep their spaces, since they are words.
+    pub tight_operators: bool,
+    /// Leaves out the space after commas in lists, e.g. `f(a, b)` becomes `f(a,b)`.
+    pub tight_commas: bool,
+}
+
+/// Renders statements and expressions as SQL text according to a set of `FormatOptions`
+#[derive(Debug, Clone, Default)]
+pub struct SqlFormatter {
+    options: FormatOptions,
+}
+
+impl SqlFormatter {
+    /// Creates a new formatter with the given options
+    pub fn new(options: FormatOptions) -> Self {
+        SqlFormatter { options }
+    }
+
+    /// Renders a whole statement, including the terminating semicolon, so the output can be parsed again
+    pub fn format_statement(&self, statement: &Statement) -> String {
+        let mut out = String::new();
+        self.write_statement(&mut out, statement);
+        out.push(';');
+        out
+    }
+
+    pub fn format_expression(&self, expr: &Expression) -> String {
+        let mut out = String::new();
+        self.write_expression(&mut out, expr);
+        out
+    }
+
+    /// Renders a column definition as it appears in `CREATE TABLE` or `ALTER TABLE ... ADD COLUMN`
+    pub fn format_column(&self, column: &TableColumn) -> String {
+        let mut out = String::new();
+        self.write_column(&mut out, column);
+        out
+    }
+
+    pub fn format_constraint(&self, constraint: &Constraint) -> String {
+        let mut out = String::new();
+        self.write_constraint(&mut out, constraint);
+        out
+    }
+
+    /// Renders a table-level constraint as it appears in `CREATE TABLE` or `ALTER TABLE ... ADD`
+    pub fn format_table_constraint(&self, constraint: &TableConstraint) -> String {
+        let mut out = String::new();
+        self.write_table_constraint(&mut out, constraint);
+        out
+    }
+
+    /// Renders one `ORDER BY` entry, with its direction after the expression (`a + b DESC`)
+    pub fn format_order_by_item(&self, item: &OrderByItem) -> String {
+        let mut out = String::new();
+        self.write_order_by_item(&mut out, item);
+        out
+    }
+
+    /// Renders a window specification including its parentheses, as written after `OVER` or `WINDOW name AS`
+    pub fn format_window_spec(&self, spec: &WindowSpec) -> String {
+        let mut out = String::new();
+        self.write_window_spec(&mut out, spec);
+        out
+    }
+
+    fn write_statement(&self, out: &mut String, statement: &Statement) {
+        match statement {
+            Statement::Select { distinct, columns, wildcard_except, wildcard_replace, from, joins, r#where, groupby, having, windows, orderby, limit, offset } => {
+                out.push_str(if *distinct { "SELECT DISTINCT " } else { "SELECT " });
+                self.write_expression_list(out, columns);
+                if !wildcard_except.is_empty() {
+                    out.push_str(&format!(" EXCEPT ({})", self.identifier_list(wildcard_except)));
+                }
+                if !wildcard_replace.is_empty() {
+                    out.push_str(" REPLACE (");
+                    for (i, (expr, column)) in wildcard_replace.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(self.comma());
+                        }
+                        self.write_expression(out, expr);
+                        out.push_str(&format!(" AS {}", self.identifier(column)));
+                    }
+                    out.push(')');
+                }
+                out.push_str(" FROM ");
+                self.write_table_ref(out, from);
+                for join in joins {
+                    out.push_str(&format!(" {} ", join.join_type));
+                    self.write_table_ref(out, &join.table);
+                    out.push_str(" ON ");
+                    self.write_expression(out, &join.on);
+                }
+                if let Some(expr) = r#where {
+                    out.push_str(" WHERE ");
+                    self.write_expression(out, expr);
+                }
+                if !groupby.is_empty() {
+                    out.push_str(" GROUP BY ");
+                    self.write_expression_list(out, groupby);
+                }
+                if let Some(expr) = having {
+                    out.push_str(" HAVING ");
+                    self.write_expression(out, expr);
+                }
+                if !windows.is_empty() {
+                    out.push_str(" WINDOW ");
+                    for (i, (name, spec)) in windows.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(self.comma());
+                        }
+                        out.push_str(&format!("{} AS ", self.identifier(name)));
+                        self.write_window_spec(out, spec);
+                    }
+                }
+                if !orderby.is_empty() {
+                    out.push_str(" ORDER BY ");
+                    for (i, item) in orderby.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(self.comma());
+                        }
+                        self.write_order_by_item(out, item);
+                    }
+                }
+                if let Some(expr) = limit {
+                    out.push_str(" LIMIT ");
+                    self.write_expression(out, expr);
+                }
+                if let Some(expr) = offset {
+                    out.push_str(" OFFSET ");
+                    self.write_expression(out, expr);
+                }
+            }
+            Statement::CreateTable { table_name, temporary, column_list, table_constraints, options } => {
+                out.push_str("CREATE ");
+                if *temporary {
+                    out.push_str("TEMPORARY ");
+                }
+                out.push_str(&format!("TABLE {} (", self.identifier(table_name)));
+                for (i, column) in column_list.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(self.comma());
+                    }
+                    self.write_column(out, column);
+                }
+                for constraint in table_constraints {
+                    out.push_str(self.comma());
+                    self.write_table_constraint(out, constraint);
+                }
+                out.push(')');
+                for (name, value) in options {
+                    // Values that do not read back as a single identifier or number are quoted
+                    let is_number = !value.is_empty() && value.chars().all(|c| c.is_ascii_digit());
+                    let is_identifier = value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                        && value.chars().all(|c| c.is_alphanumeric() || c == '_');
+                    if is_number || is_identifier {
+                        out.push_str(&format!(" {}={}", name, value));
+                    } else {
+                        out.push_str(&format!(" {}={}", name, quote_string(value)));
+                    }
+                }
+            }
+            Statement::Update { table_name, assignments, r#where } => {
+                out.push_str(&format!("UPDATE {} SET ", self.identifier(table_name)));
+                self.write_assignments(out, assignments);
+                match r#where {
+                    Some(WhereClause::Expression(expr)) => {
+                        out.push_str(" WHERE ");
+                        self.write_expression(out, expr);
+                    }
+                    Some(WhereClause::CurrentOf(cursor)) => out.push_str(&format!(" WHERE CURRENT OF {}", self.identifier(cursor))),
+                    None => {}
+                }
+            }
+            Statement::DropTable { table_names, if_exists, drop_behavior } => {
+                out.push_str("DROP TABLE ");
+                if *if_exists {
+                    out.push_str("IF EXISTS ");
+                }
+                out.push_str(&self.identifier_list(table_names));
+                if let Some(behavior) = drop_behavior {
+                    out.push_str(&format!(" {}", behavior));
+                }
+            }
+            Statement::Fetch { direction, cursor } => self.write_cursor_operation(out, "FETCH", direction, cursor),
+            Statement::Move { direction, cursor } => self.write_cursor_operation(out, "MOVE", direction, cursor),
+            Statement::AlterTable { table_name, action } => {
+                out.push_str(&format!("ALTER TABLE {} ", self.identifier(table_name)));
+                match action {
+                    AlterAction::AddColumn(column) => {
+                        out.push_str("ADD COLUMN ");
+                        self.write_column(out, column);
+                    }
+                    AlterAction::AddConstraint(constraint) => {
+                        out.push_str("ADD ");
+                        self.write_table_constraint(out, constraint);
+                    }
+                    AlterAction::DropConstraint(name) => out.push_str(&format!("DROP CONSTRAINT {}", self.identifier(name))),
+                    AlterAction::DropPrimaryKey => out.push_str("DROP PRIMARY KEY"),
+                }
+            }
+            Statement::Insert { table_name, columns, source, returning } => {
+                out.push_str(&format!("INSERT INTO {}", self.identifier(table_name)));
+                if !columns.is_empty() {
+                    out.push_str(&format!(" ({})", self.identifier_list(columns)));
+                }
+                match source {
+                    InsertSource::Values(rows) => {
+                        out.push_str(" VALUES ");
+                        for (i, row) in rows.iter().enumerate() {
+                            if i > 0 {
+                                out.push_str(self.comma());
+                            }
+                            out.push('(');
+                            self.write_expression_list(out, row);
+                            out.push(')');
+                        }
+                    }
+                    InsertSource::Assignments(assignments) => {
+                        out.push_str(" SET ");
+                        self.write_assignments(out, assignments);
+                    }
+                }
+                if !returning.is_empty() {
+                    out.push_str(" RETURNING ");
+                    self.write_expression_list(out, returning);
+                }
+            }
+            Statement::SetOperation { left, op, all, right } => {
+                self.write_statement(out, left);
+                out.push_str(&format!(" {} ", op));
+                if *all {
+                    out.push_str("ALL ");
+                }
+                self.write_statement(out, right);
+            }
+        }
+    }
+
+    fn write_order_by_item(&self, out: &mut String, item: &OrderByItem) {
+        self.write_expression(out, &item.expr);
+        if let Some(direction) = &item.direction {
+            out.push_str(&format!(" {}", direction));
+        }
+    }
+
+    fn write_window_spec(&self, out: &mut String, spec: &WindowSpec) {
+        // The parts are separated by single spaces, and an empty specification stays `()`
+        let mut parts = Vec::new();
+        if !spec.partition_by.is_empty() {
+            let mut part = "PARTITION BY ".to_string();
+            self.write_expression_list(&mut part, &spec.partition_by);
+            parts.push(part);
+        }
+        if !spec.order_by.is_empty() {
+            let mut part = "ORDER BY ".to_string();
+            for (i, item) in spec.order_by.iter().enumerate() {
+                if i > 0 {
+                    part.push_str(self.comma());
+                }
+                self.write_order_by_item(&mut part, item);
+            }
+            parts.push(part);
+        }
+        if let Some(frame) = &spec.frame {
+            let mut part = format!("{} ", frame.units);
+            match &frame.end {
+                Some(end) => {
+                    part.push_str("BETWEEN ");
+                    self.write_frame_bound(&mut part, &frame.start);
+                    part.push_str(" AND ");
+                    self.write_frame_bound(&mut part, end);
+                }
+                None => self.write_frame_bound(&mut part, &frame.start),
+            }
+            if let Some(exclude) = &frame.exclude {
+                part.push_str(&format!(" EXCLUDE {}", exclude));
+            }
+            parts.push(part);
+        }
+        out.push_str(&format!("({})", parts.join(" ")));
+    }
+
+    fn write_frame_bound(&self, out: &mut String, bound: &FrameBound) {
+        match bound {
+            FrameBound::UnboundedPreceding => out.push_str("UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(offset) => {
+                self.write_expression(out, offset);
+                out.push_str(" PRECEDING");
+            }
+            FrameBound::CurrentRow => out.push_str("CURRENT ROW"),
+            FrameBound::Following(offset) => {
+                self.write_expression(out, offset);
+                out.push_str(" FOLLOWING");
+            }
+            FrameBound::UnboundedFollowing => out.push_str("UNBOUNDED FOLLOWING"),
+        }
+    }
+
+    fn write_cursor_operation(&self, out: &mut String, keyword: &str, direction: &Option<FetchDirection>, cursor: &str) {
+        match direction {
+            Some(direction) => out.push_str(&format!("{} {} FROM {}", keyword, direction, self.identifier(cursor))),
+            None => out.push_str(&format!("{} {}", keyword, self.identifier(cursor))),
+        }
+    }
+
+    fn write_table_constraint(&self, out: &mut String, constraint: &TableConstraint) {
+        let name = match constraint {
+            TableConstraint::PrimaryKey { name, .. } | TableConstraint::ForeignKey { name, .. } => name,
+        };
+        if let Some(name) = name {
+            out.push_str(&format!("CONSTRAINT {} ", self.identifier(name)));
+        }
+        match constraint {
+            TableConstraint::PrimaryKey { columns, .. } => out.push_str(&format!("PRIMARY KEY ({})", self.identifier_list(columns))),
+            TableConstraint::ForeignKey { columns, table, referenced_columns, on_delete, on_update, match_type, .. } => {
+                out.push_str(&format!("FOREIGN KEY ({}) REFERENCES {}", self.identifier_list(columns), self.identifier(table)));
+                if !referenced_columns.is_empty() {
+                    out.push_str(&format!("({})", self.identifier_list(referenced_columns)));
+                }
+                self.write_reference_clauses(out, on_delete, on_update, match_type);
+            }
+        }
+    }
+
+    fn write_column(&self, out: &mut String, column: &TableColumn) {
+        // An auto-increment integer is written back as the serial pseudo-type it was parsed from
+        let serial = match column.column_type {
+            DBType::Int => Some("SERIAL"),
+            DBType::SmallInt => Some("SMALLSERIAL"),
+            DBType::BigInt => Some("BIGSERIAL"),
+            _ => None,
+        }
+        .filter(|_| column.constraints.contains(&Constraint::AutoIncrement));
+
+        match serial {
+            Some(serial) => out.push_str(&format!("{} {}", self.identifier(&column.column_name), serial)),
+            None => out.push_str(&format!("{} {}", self.identifier(&column.column_name), column.column_type)),
+        }
+        for constraint in &column.constraints {
+            if serial.is_some() && *constraint == Constraint::AutoIncrement {
+                continue;
+            }
+            out.push(' ');
+            self.write_constraint(out, constraint);
+        }
+        if let Some(comment) = &column.comment {
+            out.push_str(&format!(" COMMENT {}", quote_string(comment)));
+        }
+    }
+
+    fn write_constraint(&self, out: &mut String, constraint: &Constraint) {
+        match constraint {
+            Constraint::NotNull => out.push_str("NOT NULL"),
+            Constraint::PrimaryKey => out.push_str("PRIMARY KEY"),
+            Constraint::Unique => out.push_str("UNIQUE"),
+            // The standard spelling; inside a column definition the serial type is written instead
+            Constraint::AutoIncrement => out.push_str("GENERATED BY DEFAULT AS IDENTITY"),
+            Constraint::Check(expr) => {
+                out.push_str("CHECK (");
+                self.write_expression(out, expr);
+                out.push(')');
+            }
+            Constraint::Default(expr) => {
+                out.push_str("DEFAULT ");
+                self.write_expression(out, expr);
+            }
+            Constraint::References { table, column, on_delete, on_update, match_type } => {
+                out.push_str(&format!("REFERENCES {}", self.identifier(table)));
+                if let Some(column) = column {
+                    out.push_str(&format!("({})", self.identifier(column)));
+                }
+                self.write_reference_clauses(out, on_delete, on_update, match_type);
+            }
+        }
+    }
+
+    /// Writes the `MATCH`, `ON DELETE` and `ON UPDATE` clauses shared by `REFERENCES` and `FOREIGN KEY`
+    fn write_reference_clauses(
+        &self,
+        out: &mut String,
+        on_delete: &Option<ReferentialAction>,
+        on_update: &Option<ReferentialAction>,
+        match_type: &Option<MatchType>,
+    ) {
+        if let Some(match_type) = match_type {
+            out.push_str(&format!(" MATCH {}", match_type));
+        }
+        if let Some(action) = on_delete {
+            out.push_str(&format!(" ON DELETE {}", action));
+        }
+        if let Some(action) = on_update {
+            out.push_str(&format!(" ON UPDATE {}", action));
+        }
+    }
+
+    fn write_expression_list(&self, out: &mut String, expressions: &[Expression]) {
+        for (i, expr) in expressions.iter().enumerate() {
+            if i > 0 {
+                out.push_str(self.comma());
+            }
+            self.write_expression(out, expr);
+        }
+    }
+
+    /// Writes a table name with its alias, if it has one
+    fn write_table_ref(&self, out: &mut String, table: &TableRef) {
+        out.push_str(&self.identifier(&table.name));
+        if let Some(alias) = &table.alias {
+            out.push_str(&format!(" AS {}", self.identifier(alias)));
+        }
+    }
+
+    /// Writes the `a = 1, b = 2` list of an `UPDATE` or MySQL `INSERT ... SET`
+    fn write_assignments(&self, out: &mut String, assignments: &[(String, Expression)]) {
+        for (i, (column, value)) in assignments.iter().enumerate() {
+            if i > 0 {
+                out.push_str(self.comma());
+            }
+            out.push_str(&format!("{} = ", self.identifier(column)));
+            self.write_expression(out, value);
+        }
+    }
+
+    fn write_expression(&self, out: &mut String, expr: &Expression) {
+        match expr {
+            Expression::BinaryOperation { left_operand, operator, right_operand } => {
+                let operator = self.dialect_operator(operator);
+                let precedence = binary_precedence(operator);
+                // Every binary operator is left-associative, so a left operand of the same
+                // precedence reads back identically without parentheses, while a right operand
+                // of the same precedence must keep them: `a - (b - c)` is not `a - b - c`.
+                self.write_operand(out, left_operand, precedence > self.expression_precedence(left_operand));
+                let tight = self.options.tight_operators && !matches!(operator, BinaryOperator::And | BinaryOperator::Or);
+                if tight {
+                    out.push_str(&operator.to_string());
+                } else {
+                    out.push_str(&format!(" {} ", operator));
+                }
+                let right_start = out.len();
+                self.write_operand(out, right_operand, precedence >= self.expression_precedence(right_operand));
+                // `a - -b` must not be written as `a--b`, which would start a comment
+                if tight && out[..right_start].ends_with('-') && out[right_start..].starts_with('-') {
+                    out.insert(right_start, ' ');
+                }
+            }
+            Expression::UnaryOperation { operand, operator } => match operator {
+                UnaryOperator::Not => {
+                    out.push_str("NOT ");
+                    // `NOT EXISTS (...)` would read back as a single negated EXISTS
+                    let exists = matches!(operand.as_ref(), Expression::Exists { negated: false, .. });
+                    self.write_operand(out, operand, exists || UNARY_PRECEDENCE > self.expression_precedence(operand));
+                }
+                UnaryOperator::Plus | UnaryOperator::Minus => {
+                    out.push_str(&operator.to_string());
+                    // A nested sign is parenthesized so that `- -a` never turns into `--a`
+                    let nested_sign = matches!(
+                        operand.as_ref(),
+                        Expression::UnaryOperation { operator: UnaryOperator::Plus | UnaryOperator::Minus, .. }
+                    );
+                    self.write_operand(out, operand, nested_sign || UNARY_PRECEDENCE > self.expression_precedence(operand));
+                }
+            },
+            Expression::Number(num) => out.push_str(&self.format_number(*num)),
+            Expression::Float(num) => out.push_str(&format_float(*num)),
+            Expression::Identifier(iden) => out.push_str(&self.identifier(iden)),
+            Expression::QualifiedIdentifier { qualifier, name } => {
+                out.push_str(&format!("{}.{}", self.identifier(qualifier), self.identifier(name)))
+            }
+            Expression::String(str) => out.push_str(&quote_string(str)),
+            Expression::Bool(b) => out.push_str(&b.to_string()),
+            Expression::Null => out.push_str("NULL"),
+            Expression::TypedLiteral { ty, value } => out.push_str(&format!("{} {}", ty, quote_string(value))),
+            Expression::FunctionCall { name, distinct, args } => {
+                out.push_str(name);
+                out.push('(');
+                if *distinct {
+                    out.push_str("DISTINCT ");
+                }
+                self.write_expression_list(out, args);
+                out.push(')');
+            }
+            Expression::Aliased { expr, alias } => {
+                self.write_expression(out, expr);
+                out.push_str(&format!(" AS {}", self.identifier(alias)));
+            }
+            Expression::Case { operand, when_clauses, else_clause } => {
+                out.push_str("CASE");
+                if let Some(operand) = operand {
+                    out.push(' ');
+                    self.write_expression(out, operand);
+                }
+                for (condition, result) in when_clauses {
+                    out.push_str(" WHEN ");
+                    self.write_expression(out, condition);
+                    out.push_str(" THEN ");
+                    self.write_expression(out, result);
+                }
+                if let Some(else_clause) = else_clause {
+                    out.push_str(" ELSE ");
+                    self.write_expression(out, else_clause);
+                }
+                out.push_str(" END");
+            }
+            Expression::Exists { subquery, negated } => {
+                out.push_str(if *negated { "NOT EXISTS (" } else { "EXISTS (" });
+                self.write_statement(out, subquery);
+                out.push(')');
+            }
+            Expression::WindowFunction { function, window } => {
+                self.write_expression(out, function);
+                out.push_str(" OVER ");
+                match window {
+                    Window::Named(name) => out.push_str(&self.identifier(name)),
+                    Window::Spec(spec) => self.write_window_spec(out, spec),
+                }
+            }
+            Expression::IsNull { expr, negated } => {
+                self.write_operand(out, expr, COMPARE_PRECEDENCE > self.expression_precedence(expr));
+                out.push_str(if *negated { " IS NOT NULL" } else { " IS NULL" });
+            }
+            Expression::Between { expr, low, high, negated } => {
+                self.write_operand(out, expr, COMPARE_PRECEDENCE > self.expression_precedence(expr));
+                out.push_str(if *negated { " NOT BETWEEN " } else { " BETWEEN " });
+                self.write_operand(out, low, COMPARE_PRECEDENCE >= self.expression_precedence(low));
+                out.push_str(" AND ");
+                self.write_operand(out, high, COMPARE_PRECEDENCE >= self.expression_precedence(high));
+            }
+            Expression::InList { expr, list, negated } => {
+                self.write_operand(out, expr, COMPARE_PRECEDENCE > self.expression_precedence(expr));
+                out.push_str(if *negated { " NOT IN (" } else { " IN (" });
+                self.write_expression_list(out, list);
+                out.push(')');
+            }
+            Expression::InSubquery { expr, subquery, negated } => {
+                self.write_operand(out, expr, COMPARE_PRECEDENCE > self.expression_precedence(expr));
+                out.push_str(if *negated { " NOT IN (" } else { " IN (" });
+                self.write_statement(out, subquery);
+                out.push(')');
+            }
+            Expression::Subquery(subquery) => {
+                out.push('(');
+                self.write_statement(out, subquery);
+                out.push(')');
+            }
+            Expression::IsDistinctFrom { left, right, negated } => {
+                self.write_operand(out, left, COMPARE_PRECEDENCE > self.expression_precedence(left));
+                out.push_str(if *negated { " IS NOT DISTINCT FROM " } else { " IS DISTINCT FROM " });
+                self.write_operand(out, right, COMPARE_PRECEDENCE >= self.expression_precedence(right));
+            }
+            Expression::Like { expr, pattern, negated } => {
+                self.write_operand(out, expr, COMPARE_PRECEDENCE > self.expression_precedence(expr));
+                out.push_str(if *negated { " NOT LIKE " } else { " LIKE " });
+                self.write_operand(out, pattern, COMPARE_PRECEDENCE >= self.expression_precedence(pattern));
+            }
+        }
+    }
+
+    fn write_operand(&self, out: &mut String, operand: &Expression, parenthesize: bool) {
+        if parenthesize {
+            out.push('(');
+            self.write_expression(out, operand);
+            out.push(')');
+        } else {
+            self.write_expression(out, operand);
+        }
+    }
+
+    /// Renders `name` as an identifier, quoted when it would otherwise be read as a keyword or as more than one token
+    /// MySQL quotes identifiers with backticks, every other dialect with double quotes. The `*` of a wildcard is left as it is.
+    fn identifier<'b>(&self, name: &'b str) -> Cow<'b, str> {
+        let plain = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+            && !keyword(name).is_some_and(|keyword| keyword.is_reserved());
+        if plain || name == "*" {
+            return Cow::Borrowed(name);
+        }
+        let quote = if self.options.dialect == Dialect::MySql { '`' } else { '"' };
+        Cow::Owned(format!("{0}{1}{0}", quote, name.replace(quote, &format!("{0}{0}", quote))))
+    }
+
+    /// Renders a list of names, like the columns of `INSERT INTO t (a, b)`, with each name quoted as needed
+    fn identifier_list(&self, names: &[String]) -> String {
+        names.iter().map(|name| self.identifier(name)).collect::<Vec<_>>().join(self.comma())
+    }
+
+    /// Separator written between the items of a list
+    fn comma(&self) -> &'static str {
+        if self.options.tight_commas {
+            ","
+        } else {
+            ", "
+        }
+    }
+
+    /// The operator as the target dialect reads it
+    /// MySQL reads `||` as a logical OR, so concatenation is written, and parenthesized, as `OR` there.
+    fn dialect_operator<'b>(&self, operator: &'b BinaryOperator) -> &'b BinaryOperator {
+        match operator {
+            BinaryOperator::Concat if self.options.dialect == Dialect::MySql => &BinaryOperator::Or,
+            _ => operator,
+        }
+    }
+
+    /// Binding strength of an expression when it appears as an operand
+    /// Aliases bind looser than anything else, while literals and identifiers never need parentheses.
+    fn expression_precedence(&self, expr: &Expression) -> u8 {
+        match expr {
+            Expression::BinaryOperation { operator, .. } => binary_precedence(self.dialect_operator(operator)),
+            Expression::Aliased { .. } => 0,
+            Expression::UnaryOperation { .. } => UNARY_PRECEDENCE,
+            Expression::Like { .. } | Expression::InList { .. } | Expression::InSubquery { .. } | Expression::Between { .. }
+            | Expression::IsNull { .. }
+            | Expression::IsDistinctFrom { .. } => COMPARE_PRECEDENCE,
+            _ => u8::MAX,
+        }
+    }
+
+    /// Renders a numeric literal, grouping its digits when `group_digits` is enabled
+    fn format_number(&self, num: u64) -> String {
+        let digits = num.to_string();
+        if !self.options.group_digits {
+            return digits;
+        }
+
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push('_');
+            }
+            grouped.push(c);
+        }
+        grouped
+    }
+}
+
+/// Wraps a string literal in single quotes, doubling any quote inside it as the tokenizer expects
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Precedence of `<`, `>`, `LIKE`, `IN`, `BETWEEN`, `IS`, ..., matching `Precedence::Compare` in the parser
+const COMPARE_PRECEDENCE: u8 = 4;
+
+/// Precedence of prefix `-`, `+` and `NOT`, matching `Precedence::Unary` in the parser
+const UNARY_PRECEDENCE: u8 = 7;
+
+/// Binding strength of a binary operator, matching the parser's `Precedence` levels
+fn binary_precedence(operator: &BinaryOperator) -> u8 {
+    match operator {
+        BinaryOperator::Or => 1,
+        BinaryOperator::And => 2,
+        BinaryOperator::Equal | BinaryOperator::NotEqual | BinaryOperator::NullSafeEqual => 3,
+        BinaryOperator::GreaterThan | BinaryOperator::GreaterThanOrEqual |
+        BinaryOperator::LessThan | BinaryOperator::LessThanOrEqual => COMPARE_PRECEDENCE,
+        BinaryOperator::Plus | BinaryOperator::Minus | BinaryOperator::Concat => 5,
+        BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => 6,
+    }
+}
+
+/// Renders a floating-point literal in plain decimal notation, always with a decimal point
+/// `Debug` would switch to exponent notation (`1e-5`), which the tokenizer does not read, and
+/// `Display` drops the point from whole numbers (`2`), which would read back as an integer.
+fn format_float(num: f64) -> String {
+    let text = num.to_string();
+    if text.contains('.') {
+        text
+    } else {
+        format!("{}.0", text)
+    }
+}
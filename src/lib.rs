@@ -0,0 +1,52 @@
+/// SQL parser library
+/// The modules are public so the tokenizer, parser and AST can be used on their own,
+/// while `parse` covers the common case of turning one SQL statement into a `Statement`.
+pub mod statement;
+pub mod error;
+pub mod token;
+pub mod tokenizer;
+pub mod parser;
+pub mod dialect;
+pub mod dot;
+pub mod formatter;
+pub mod lint;
+pub mod rewrite;
+
+use std::ops::Range;
+
+use dialect::Dialect;
+use error::ParseError;
+use parser::Parser;
+use statement::Statement;
+use tokenizer::Tokenizer;
+
+/// Parses a single SQL statement, with or without a trailing semicolon, e.g. `parse("SELECT * FROM t")`
+/// Nothing but whitespace and comments may follow the statement; use `parse_file` for several statements.
+pub fn parse(sql: &str) -> Result<Statement, ParseError> {
+    let tokenizer = Tokenizer::new(sql);
+    let mut parser = Parser::new(tokenizer);
+    parser.parse_only_statement(false)
+}
+
+/// Parses a single SQL statement like `parse`, but requires it to end with exactly one semicolon
+pub fn parse_strict(sql: &str) -> Result<Statement, ParseError> {
+    let tokenizer = Tokenizer::new(sql);
+    let mut parser = Parser::new(tokenizer);
+    parser.parse_only_statement(true)
+}
+
+/// Parses a single SQL statement like `parse`, additionally accepting the syntax extensions of `dialect`
+pub fn parse_with_dialect(sql: &str, dialect: Dialect) -> Result<Statement, ParseError> {
+    let tokenizer = Tokenizer::new(sql);
+    let mut parser = Parser::with_dialect(tokenizer, dialect);
+    parser.parse_only_statement(false)
+}
+
+/// Parses every statement in `input`, such as the contents of a `.sql` file
+/// Each statement, or the error it failed with, comes with the byte range of its source text, so
+/// diagnostics can point at the offending statement while the statements around it are still checked.
+pub fn parse_file(input: &str) -> Vec<(Result<Statement, ParseError>, Range<usize>)> {
+    let tokenizer = Tokenizer::new(input);
+    let mut parser = Parser::new(tokenizer);
+    parser.parse_statements()
+}
@@ -0,0 +1,45 @@
+/// Lint module for parsed expressions
+/// This module contains best-effort checks that flag SQL which parses fine but is likely to
+/// behave differently across databases. Lints return warnings instead of failing the parse.
+use crate::statement::Expression;
+
+/// The character most dialects treat as the `LIKE` escape when no `ESCAPE` clause is given
+const DEFAULT_LIKE_ESCAPE: char = '\\';
+
+/// Checks a `LIKE` pattern for dangling escape characters
+/// Without an `ESCAPE` clause, whether `\` escapes anything is dialect-specific, so a backslash
+/// at the end of the pattern, or one that is not followed by `%`, `_` or another `\`, is reported.
+/// Returns `None` for expressions that are not `LIKE` or whose pattern is not a string literal.
+pub fn check_like_escapes(expr: &Expression) -> Option<String> {
+    let pattern = match expr {
+        Expression::Like { pattern, .. } => match pattern.as_ref() {
+            Expression::String(pattern) => pattern,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let mut chars = pattern.chars().enumerate();
+    while let Some((position, c)) = chars.next() {
+        if c != DEFAULT_LIKE_ESCAPE {
+            continue;
+        }
+        match chars.next() {
+            Some((_, '%' | '_')) => {}
+            Some((_, next)) if next == DEFAULT_LIKE_ESCAPE => {}
+            Some((_, next)) => {
+                return Some(format!(
+                    "LIKE pattern '{}' escapes '{}' at position {}, which is not a wildcard",
+                    pattern, next, position
+                ));
+            }
+            None => {
+                return Some(format!(
+                    "LIKE pattern '{}' ends with a dangling escape character",
+                    pattern
+                ));
+            }
+        }
+    }
+    None
+}
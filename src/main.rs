@@ -1,23 +1,22 @@
-mod statement;
-mod token;
-mod tokenizer;
-mod parser;
-
 use std::io::{self, Write};
-use tokenizer::Tokenizer;
-use parser::Parser;
+use sql_parser::parser::Parser;
+use sql_parser::statement::Statement;
+use sql_parser::token::Token;
+use sql_parser::tokenizer::Tokenizer;
 
 fn main() -> io::Result<()> {
     println!("Welcome to the SQL Parser!");
     println!("Enter SQL queries (each ending with a semicolon) or type 'exit' to quit.");
     println!("For multi-line queries, press Enter after each line.");
     println!("Press Enter twice to force-parse an incomplete query.");
+    println!("Type 'DELIMITER //' to end queries with // instead of a semicolon.");
     println!();
 
     let mut input = String::new();
     let mut current_query = String::new();
     let mut in_multiline = false;
     let mut empty_line_count = 0;
+    let mut delimiter = String::from(";");
     
     loop {
         if !in_multiline {
@@ -34,17 +33,30 @@ fn main() -> io::Result<()> {
         if !in_multiline && trimmed_input.eq_ignore_ascii_case("exit") {
             break;
         }
+
+        // `DELIMITER //` changes the terminator that ends a query, like the MySQL client
+        let (command, argument) = trimmed_input.split_once(char::is_whitespace).unwrap_or((trimmed_input, ""));
+        if !in_multiline && command.eq_ignore_ascii_case("delimiter") {
+            match argument.trim() {
+                "" => println!("Error: DELIMITER requires a terminator, e.g. DELIMITER //\n"),
+                new_delimiter => {
+                    delimiter = new_delimiter.to_string();
+                    println!("Delimiter set to {}\n", delimiter);
+                }
+            }
+            continue;
+        }
         
         if trimmed_input.is_empty() {
             empty_line_count += 1;
             if empty_line_count >= 2 && !current_query.is_empty() {
                 // Force parse the incomplete query
                 println!("Parsing incomplete query...");
-                match parse_sql(&current_query) {
+                match parse_sql(&current_query, &delimiter) {
                     Ok(statement) => println!("{:#?}\n", statement),
                     Err(e) => {
-                        if !current_query.contains(';') {
-                            println!("Error: Missing semicolon at the end of the query");
+                        if find_statement_end(&current_query, &delimiter).is_none() {
+                            println!("Error: Missing {} at the end of the query", delimiter);
                         }
                         println!("Error: {}", e);
                         println!("Current query: {}\n", current_query);
@@ -58,16 +70,17 @@ fn main() -> io::Result<()> {
         }
         empty_line_count = 0;
 
-        // Add the input to the current query
+        // Add the input to the current query, keeping line breaks so that a
+        // `--` comment only runs to the end of its own line
         if !current_query.is_empty() {
-            current_query.push(' ');
+            current_query.push('\n');
         }
         current_query.push_str(trimmed_input);
         
-        // If we see a semicolon, try to parse the complete statement
-        if trimmed_input.contains(';') {
+        // If we see the delimiter, try to parse the complete statement
+        if find_statement_end(&current_query, &delimiter).is_some() {
             in_multiline = false;
-            match parse_sql(&current_query) {
+            match parse_sql(&current_query, &delimiter) {
                 Ok(statement) => println!("{:#?}\n", statement),
                 Err(e) => {
                     println!("Error: {}", e);
@@ -76,7 +89,7 @@ fn main() -> io::Result<()> {
             }
             current_query.clear();
         } else {
-            // No semicolon yet, continue collecting input
+            // No delimiter yet, continue collecting input
             in_multiline = true;
         }
     }
@@ -85,7 +98,18 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-fn parse_sql(input: &str) -> Result<statement::Statement, String> {
+/// Byte offset of the delimiter that ends the first query in `query`, if it has been typed yet
+/// The query is tokenized, so a delimiter inside a string or comment does not count. Tokenizer errors
+/// are skipped: an unterminated string may still be closed on the next line, and any other error is
+/// reported when the query is parsed.
+fn find_statement_end(query: &str, delimiter: &str) -> Option<usize> {
+    Tokenizer::with_terminator(query, delimiter).find_map(|token| match token {
+        Ok(spanned) if spanned.value == Token::Semicolon => Some(spanned.start),
+        _ => None,
+    })
+}
+
+fn parse_sql(input: &str, delimiter: &str) -> Result<Statement, String> {
     // Pre-parse validation
     if input.trim().is_empty() {
         return Err("Empty query".to_string());
@@ -97,13 +121,13 @@ fn parse_sql(input: &str) -> Result<statement::Statement, String> {
         if !lowercase_input.contains("from") {
             return Err("SELECT statement must contain FROM clause".to_string());
         }
-    } else if lowercase_input.starts_with("create table") {
-        if lowercase_input.contains("varchar") && !lowercase_input.contains("varchar(") {
-            return Err("VARCHAR type must specify length using VARCHAR(n)".to_string());
-        }
+    } else if lowercase_input.starts_with("create table")
+        && lowercase_input.contains("varchar")
+        && !lowercase_input.contains("varchar(")
+    {
+        return Err("VARCHAR type must specify length using VARCHAR(n)".to_string());
     }
 
-    let tokenizer = Tokenizer::new(input);
-    let mut parser = Parser::new(tokenizer);
-    parser.parse_statement()
+    let mut parser = Parser::new(Tokenizer::with_terminator(input, delimiter));
+    parser.parse_only_statement(false).map_err(|e| e.to_string())
 }
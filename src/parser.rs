@@ -1,451 +1,2356 @@
-/// Parser module for SQL statements
-/// This module implements a Pratt parser for SQL expressions and statements.
-/// It handles both SELECT and CREATE TABLE statements with their various clauses.
-use crate::statement::{Expression, BinaryOperator, UnaryOperator, Statement, TableColumn, DBType, Constraint};
-use crate::token::{Token, Keyword};
-use std::iter::Peekable;
-
-/// Parser struct that handles the parsing of SQL statements
-/// It uses a peekable iterator of tokens as input and maintains the current token being processed
-pub struct Parser<I: Iterator<Item = Result<Token, String>>> {
-    tokens: Peekable<I>,
-    current_token: Option<Token>,
-}
-
-/// Operator precedence levels for the Pratt parser
-/// Higher numbers indicate higher precedence
-#[derive(Debug, PartialEq, PartialOrd)]
-enum Precedence {
-    None = 0,
-    Or = 1,      // OR operator
-    And = 2,     // AND operator
-    Equality = 3, // =, != comparisons
-    Compare = 4,  // <, >, <=, >= comparisions
-    Term = 5,     // +, - arithmetic
-    Factor = 6,   // *, / arithmetic
-    Unary = 7,    // -, NOT unary operations
-    Primary = 8,  // literals, identifiers, parentheses
-}
-
-impl<I: Iterator<Item = Result<Token, String>>> Parser<I> {
-    /// Creates a new Parser instance with the given token iterator
-    pub fn new(tokens: I) -> Self {
-        let mut parser = Parser {
-            tokens: tokens.peekable(),
-            current_token: None,
-        };
-        parser.advance();
-        parser
-    }
-
-    fn advance(&mut self) -> Option<Token> {
-        self.current_token = self.tokens.next().and_then(|result| result.ok());
-        self.current_token.clone()
-    }
-
-    fn peek_token(&mut self) -> Option<Token> {
-        self.tokens.peek().and_then(|result| result.as_ref().ok().cloned())
-    }
-
-    fn expect_token(&mut self, expected: Token) -> Result<(), String> {
-        match self.current_token.clone() {
-            Some(token) if token == expected => {
-                self.advance();
-                Ok(())
-            }
-            Some(token) => Err(format!("Expected {:?}, got {:?}", expected, token)),
-            None => Err("Unexpected end of input".to_string()),
-        }
-    }
-
-    fn expect_keyword(&mut self, expected: Keyword) -> Result<(), String> {
-        match self.current_token.clone() {
-            Some(Token::Keyword(keyword)) if keyword == expected => {
-                self.advance();
-                Ok(())
-            }
-            Some(token) => Err(format!("Expected keyword {:?}, got {:?}", expected, token)),
-            None => Err("Unexpected end of input".to_string()),
-        }
-    }
-
-    fn get_precedence(&self, token: &Token) -> Precedence {
-        match token {
-            Token::Plus | Token::Minus => Precedence::Term,
-            Token::Multiply | Token::Divide => Precedence::Factor,
-            Token::Equal | Token::NotEqual => Precedence::Equality,
-            Token::GreaterThan | Token::GreaterThanOrEqual |
-            Token::LessThan | Token::LessThanOrEqual => Precedence::Compare,
-            Token::Keyword(Keyword::And) => Precedence::And,
-            Token::Keyword(Keyword::Or) => Precedence::Or,
-            _ => Precedence::None,
-        }
-    }
-
-    pub fn parse_statement(&mut self) -> Result<Statement, String> {
-        match self.current_token.clone() {
-            Some(Token::Keyword(Keyword::Select)) => self.parse_select(),
-            Some(Token::Keyword(Keyword::Create)) => self.parse_create_table(),
-            Some(token) => Err(format!("Expected SELECT or CREATE, got {:?}", token)),
-            None => Err("Unexpected end of input".to_string()),
-        }
-    }
-
-    fn parse_select(&mut self) -> Result<Statement, String> {
-        self.advance(); // Skip SELECT
-
-        // Parse columns
-        let mut columns = Vec::new();
-        
-        // Handle SELECT * case
-        if let Some(Token::Multiply) = self.current_token {
-            self.advance();
-            columns.push(Expression::Identifier("*".to_string()));
-        } else {
-            // Parse column list
-            loop {
-                columns.push(self.parse_expression()?);
-                
-                match self.current_token {
-                    Some(Token::Comma) => {
-                        self.advance();
-                        continue;
-                    }
-                    Some(Token::Keyword(Keyword::From)) => break,
-                    Some(ref token) => return Err(format!("Expected FROM or comma, got {:?}", token)),
-                    None => return Err("Unexpected end of input".to_string()),
-                }
-            }
-        }
-
-        // Parse FROM clause
-        self.expect_keyword(Keyword::From)?;
-        let from = match self.current_token.take() {
-            Some(Token::Identifier(table_name)) => {
-                self.advance();
-                table_name
-            }
-            Some(token) => return Err(format!("Expected table name, got {:?}", token)),
-            None => return Err("Unexpected end of input".to_string()),
-        };
-
-        // Parse optional WHERE clause
-        let mut where_clause = None;
-        if let Some(Token::Keyword(Keyword::Where)) = self.current_token {
-            self.advance();
-            where_clause = Some(self.parse_expression()?);
-        }
-
-        // Parse optional ORDER BY clause
-        let mut orderby = Vec::new();
-        if let Some(Token::Keyword(Keyword::Order)) = self.current_token {
-            self.advance();
-            self.expect_keyword(Keyword::By)?;
-
-            loop {
-                orderby.push(self.parse_order_by_expr()?);
-                
-                match self.current_token {
-                    Some(Token::Comma) => {
-                        self.advance();
-                        continue;
-                    }
-                    Some(Token::Semicolon) | None => break,
-                    Some(ref token) => return Err(format!("Expected semicolon or comma, got {:?}", token)),
-                }
-            }
-        }
-
-        // Expect semicolon at the end
-        self.expect_token(Token::Semicolon)?;
-
-        Ok(Statement::Select {
-            columns,
-            from,
-            r#where: where_clause,
-            orderby,
-        })
-    }
-
-    fn parse_create_table(&mut self) -> Result<Statement, String> {
-        self.advance(); // Skip CREATE
-        self.expect_keyword(Keyword::Table)?;
-
-        // Parse table name
-        let table_name = match self.current_token.take() {
-            Some(Token::Identifier(name)) => {
-                self.advance();
-                name
-            }
-            Some(token) => return Err(format!("Expected table name, got {:?}", token)),
-            None => return Err("Unexpected end of input".to_string()),
-        };
-
-        // Expect opening parenthesis
-        self.expect_token(Token::LeftParentheses)?;
-
-        // Parse column definitions
-        let mut column_list = Vec::new();
-        loop {
-            let column = self.parse_column_definition()?;
-            column_list.push(column);
-
-            match self.current_token {
-                Some(Token::Comma) => {
-                    self.advance();
-                    continue;
-                }
-                Some(Token::RightParentheses) => break,
-                Some(ref token) => return Err(format!("Expected comma or closing parenthesis, got {:?}", token)),
-                None => return Err("Unexpected end of input".to_string()),
-            }
-        }
-
-        // Expect closing parenthesis and semicolon
-        self.expect_token(Token::RightParentheses)?;
-        self.expect_token(Token::Semicolon)?;
-
-        Ok(Statement::CreateTable {
-            table_name,
-            column_list,
-        })
-    }
-
-    fn parse_column_definition(&mut self) -> Result<TableColumn, String> {
-        // Parse column name
-        let column_name = match &self.current_token {
-            Some(Token::Identifier(name)) => {
-                let name = name.clone();
-                self.advance();
-                name
-            }
-            Some(token) => return Err(format!("Expected column name identifier, got {:?}", token)),
-            None => return Err("Unexpected end of input while parsing column name".to_string()),
-        };
-
-        // Parse column type
-        let column_type = match &self.current_token {
-            Some(Token::Keyword(Keyword::Int)) => {
-                self.advance();
-                DBType::Int
-            }
-            Some(Token::Keyword(Keyword::Bool)) => {
-                self.advance();
-                DBType::Bool
-            }
-            Some(Token::Keyword(Keyword::Varchar)) => {
-                self.advance();
-                self.expect_token(Token::LeftParentheses)
-                    .map_err(|_| "Expected '(' after VARCHAR".to_string())?;
-                
-                let length = match &self.current_token {
-                    Some(Token::Number(n)) => {
-                        let length = *n as usize;
-                        self.advance();
-                        length
-                    }
-                    Some(token) => return Err(format!("Expected number for VARCHAR length, got {:?}", token)),
-                    None => return Err("Unexpected end of input while parsing VARCHAR length".to_string()),
-                };
-                
-                self.expect_token(Token::RightParentheses)
-                    .map_err(|_| "Expected ')' after VARCHAR length".to_string())?;
-                DBType::Varchar(length)
-            }
-            Some(token) => return Err(format!("Expected column type (INT, BOOL, or VARCHAR), got {:?}", token)),
-            None => return Err("Unexpected end of input while parsing column type".to_string()),
-        };
-
-        // Parse optional constraints
-        let mut constraints = Vec::new();
-        loop {
-            match &self.current_token {
-                Some(Token::Keyword(Keyword::Primary)) => {
-                    self.advance();
-                    match &self.current_token {
-                        Some(Token::Keyword(Keyword::Key)) => {
-                            self.advance();
-                            constraints.push(Constraint::PrimaryKey);
-                        }
-                        Some(token) => return Err(format!("Expected KEY after PRIMARY, got {:?}", token)),
-                        None => return Err("Unexpected end of input after PRIMARY".to_string()),
-                    }
-                }
-                Some(Token::Keyword(Keyword::Not)) => {
-                    self.advance();
-                    match &self.current_token {
-                        Some(Token::Keyword(Keyword::Null)) => {
-                            self.advance();
-                            constraints.push(Constraint::NotNull);
-                        }
-                        Some(token) => return Err(format!("Expected NULL after NOT, got {:?}", token)),
-                        None => return Err("Unexpected end of input after NOT".to_string()),
-                    }
-                }
-                Some(Token::Keyword(Keyword::Check)) => {
-                    self.advance();
-                    match &self.current_token {
-                        Some(Token::LeftParentheses) => {
-                            self.advance();
-                            let expr = self.parse_expression()?;
-                            match &self.current_token {
-                                Some(Token::RightParentheses) => {
-                                    self.advance();
-                                    constraints.push(Constraint::Check(expr));
-                                }
-                                Some(token) => return Err(format!("Expected ')' after CHECK expression, got {:?}", token)),
-                                None => return Err("Unexpected end of input in CHECK constraint".to_string()),
-                            }
-                        }
-                        Some(token) => return Err(format!("Expected '(' after CHECK, got {:?}", token)),
-                        None => return Err("Unexpected end of input after CHECK".to_string()),
-                    }
-                }
-                _ => break,
-            }
-        }
-
-        Ok(TableColumn {
-            column_name,
-            column_type,
-            constraints,
-        })
-    }
-
-    pub fn parse_expression(&mut self) -> Result<Expression, String> {
-        self.parse_expression_with_precedence(Precedence::None)
-    }
-
-    fn parse_expression_with_precedence(&mut self, precedence: Precedence) -> Result<Expression, String> {
-        let mut left = self.parse_prefix()?;
-
-        while let Some(token) = self.current_token.clone() {
-            let current_precedence = self.get_precedence(&token);
-            if precedence >= current_precedence {
-                break;
-            }
-            left = self.parse_infix(left)?;
-        }
-
-        Ok(left)
-    }
-
-    fn parse_prefix(&mut self) -> Result<Expression, String> {
-        match self.current_token.take() {
-            Some(Token::Number(n)) => {
-                self.advance();
-                Ok(Expression::Number(n))
-            }
-            Some(Token::String(s)) => {
-                self.advance();
-                Ok(Expression::String(s))
-            }
-            Some(Token::Identifier(i)) => {
-                self.advance();
-                Ok(Expression::Identifier(i))
-            }
-            Some(Token::Keyword(Keyword::True)) => {
-                self.advance();
-                Ok(Expression::Bool(true))
-            }
-            Some(Token::Keyword(Keyword::False)) => {
-                self.advance();
-                Ok(Expression::Bool(false))
-            }
-            Some(Token::LeftParentheses) => {
-                self.advance();
-                let expr = self.parse_expression()?;
-                match self.current_token {
-                    Some(Token::RightParentheses) => {
-                        self.advance();
-                        Ok(expr)
-                    }
-                    Some(ref token) => Err(format!("Expected closing parenthesis, got {:?}", token)),
-                    None => Err("Expected closing parenthesis, got end of input".to_string()),
-                }
-            }
-            Some(Token::Minus) => {
-                self.advance();
-                let expr = self.parse_expression_with_precedence(Precedence::Unary)?;
-                Ok(Expression::UnaryOperation {
-                    operand: Box::new(expr),
-                    operator: UnaryOperator::Minus,
-                })
-            }
-            Some(Token::Plus) => {
-                self.advance();
-                let expr = self.parse_expression_with_precedence(Precedence::Unary)?;
-                Ok(Expression::UnaryOperation {
-                    operand: Box::new(expr),
-                    operator: UnaryOperator::Plus,
-                })
-            }
-            Some(Token::Keyword(Keyword::Not)) => {
-                self.advance();
-                let expr = self.parse_expression_with_precedence(Precedence::Unary)?;
-                Ok(Expression::UnaryOperation {
-                    operand: Box::new(expr),
-                    operator: UnaryOperator::Not,
-                })
-            }
-            Some(token) => Err(format!("Unexpected token in prefix position: {:?}", token)),
-            None => Err("Unexpected end of input".to_string()),
-        }
-    }
-
-    fn parse_infix(&mut self, left: Expression) -> Result<Expression, String> {
-        match self.current_token.clone() {
-            Some(token) => {
-                let precedence = self.get_precedence(&token);
-                self.advance();
-                let right = self.parse_expression_with_precedence(precedence)?;
-                
-                let operator = match token {
-                    Token::Plus => BinaryOperator::Plus,
-                    Token::Minus => BinaryOperator::Minus,
-                    Token::Multiply => BinaryOperator::Multiply,
-                    Token::Divide => BinaryOperator::Divide,
-                    Token::GreaterThan => BinaryOperator::GreaterThan,
-                    Token::GreaterThanOrEqual => BinaryOperator::GreaterThanOrEqual,
-                    Token::LessThan => BinaryOperator::LessThan,
-                    Token::LessThanOrEqual => BinaryOperator::LessThanOrEqual,
-                    Token::Equal => BinaryOperator::Equal,
-                    Token::NotEqual => BinaryOperator::NotEqual,
-                    Token::Keyword(Keyword::And) => BinaryOperator::And,
-                    Token::Keyword(Keyword::Or) => BinaryOperator::Or,
-                    _ => return Err(format!("Invalid infix operator: {:?}", token)),
-                };
-
-                Ok(Expression::BinaryOperation {
-                    left_operand: Box::new(left),
-                    operator,
-                    right_operand: Box::new(right),
-                })
-            }
-            None => Err("Unexpected end of input".to_string()),
-        }
-    }
-
-    pub fn parse_order_by_expr(&mut self) -> Result<Expression, String> {
-        let expr = self.parse_expression()?;
-        
-        // Check for ASC/DESC
-        match self.current_token {
-            Some(Token::Keyword(Keyword::Asc)) => {
-                self.advance();
-                Ok(Expression::UnaryOperation {
-                    operand: Box::new(expr),
-                    operator: UnaryOperator::Asc,
-                })
-            }
-            Some(Token::Keyword(Keyword::Desc)) => {
-                self.advance();
-                Ok(Expression::UnaryOperation {
-                    operand: Box::new(expr),
-                    operator: UnaryOperator::Desc,
-                })
-            }
-            _ => Ok(expr), // Default to ASC if no direction specified
-        }
-    }
-}
+/// Parser module for SQL statements
+/// This module implements a Pratt parser for SQL expressions and statements.
+/// It handles both SELECT and CREATE TABLE statements with their various clauses.
+use crate::statement::{Expression, BinaryOperator, UnaryOperator, Statement, TableColumn, DBType, Constraint, AlterAction, ReferentialAction, MatchType, OrderByItem, SortDirection, WhereClause, Join, JoinType, TableRef, Window, WindowSpec, WindowFrame, FrameUnits, FrameBound, FrameExclusion, SetOp, DropBehavior, FetchDirection, TableConstraint, InsertSource};
+use crate::dialect::Dialect;
+use crate::token::{Token, Keyword, Spanned};
+use crate::tokenizer::TokenizeError;
+use crate::error::ParseError;
+use std::ops::Range;
+use std::iter::Peekable;
+
+/// Parser struct that handles the parsing of SQL statements
+/// It uses a peekable iterator of tokens as input and maintains the current token being processed
+pub struct Parser<I: Iterator<Item = Result<Spanned<Token>, TokenizeError>>> {
+    tokens: Peekable<I>,
+    current_token: Option<Token>,
+    /// Byte range of the input that `current_token` was read from
+    current_span: Option<Range<usize>>,
+    /// The tokens of the current statement read from `tokens` so far, so that `restore` can rewind to a checkpoint
+    /// The text of an identifier or string is moved out to `current_token` while it is the current token, see `load_current`.
+    history: Vec<Option<Spanned<Token>>>,
+    /// Index into `history` of the current token
+    position: usize,
+    /// Whether tokens are copied rather than moved out of `history`, which `checkpoint` turns on until the next statement
+    recording: bool,
+    /// Dialect whose syntax extensions are accepted
+    dialect: Dialect,
+    /// The error the tokenizer failed with, which ends the token stream like the end of input does
+    lex_error: Option<TokenizeError>,
+}
+
+/// The `ON DELETE` action, `ON UPDATE` action and `MATCH` type following a reference, each `None` when not written
+type ReferenceClauses = (Option<ReferentialAction>, Option<ReferentialAction>, Option<MatchType>);
+
+/// A saved parser position returned by `Parser::checkpoint`
+/// Restoring it rewinds the parser so that a failed speculative parse leaves no trace.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checkpoint {
+    position: usize,
+}
+
+/// Operator precedence levels for the Pratt parser
+/// Higher numbers indicate higher precedence
+#[derive(Debug, PartialEq, PartialOrd)]
+enum Precedence {
+    None = 0,
+    Or = 1,      // OR operator
+    And = 2,     // AND operator
+    Equality = 3, // =, != comparisons
+    Compare = 4,  // <, >, <=, >= comparisions
+    Term = 5,     // +, - arithmetic, || concatenation
+    Factor = 6,   // *, /, % arithmetic
+    Unary = 7,    // -, NOT unary operations
+    #[allow(dead_code)]
+    Primary = 8,  // literals, identifiers, parentheses
+}
+
+impl<I: Iterator<Item = Result<Spanned<Token>, TokenizeError>>> Parser<I> {
+    /// Creates a new Parser instance with the given token iterator
+    pub fn new(tokens: I) -> Self {
+        Self::with_dialect(tokens, Dialect::default())
+    }
+
+    /// Creates a new Parser instance that also accepts the syntax extensions of `dialect`
+    pub fn with_dialect(tokens: I, dialect: Dialect) -> Self {
+        let mut parser = Parser {
+            tokens: tokens.peekable(),
+            current_token: None,
+            current_span: None,
+            history: Vec::new(),
+            position: 0,
+            recording: false,
+            dialect,
+            lex_error: None,
+        };
+        let first = parser.next_token();
+        parser.history.push(first);
+        parser.load_current();
+        parser
+    }
+
+    /// Moves to the next token
+    /// The new token is loaded into `current_token`; callers that need its value take it from there
+    /// instead of getting a copy back.
+    fn advance(&mut self) {
+        self.stash_current();
+        self.position += 1;
+        // After a restore, tokens that were already read are replayed from the history
+        if self.position == self.history.len() {
+            let next = self.next_token();
+            self.history.push(next);
+        }
+        self.load_current();
+    }
+
+    /// Reads the next token from the tokenizer, recording a tokenizer error in `lex_error`
+    fn next_token(&mut self) -> Option<Spanned<Token>> {
+        match self.tokens.next()? {
+            Ok(spanned) => Some(spanned),
+            Err(error) => {
+                self.lex_error.get_or_insert(error);
+                None
+            }
+        }
+    }
+
+    /// Sets `current_token` and `current_span` from the history entry at `position`
+    /// The text of an identifier or string is moved out, leaving an empty one behind, and `stash_current` puts
+    /// it back unless a caller took it. The kind and span of the token stay in the history either way, which is
+    /// all that skipping or rescanning a failed statement looks at. Only while a checkpoint is recording is the
+    /// text copied, so that `restore` can hand it out again.
+    fn load_current(&mut self) {
+        let recording = self.recording;
+        match &mut self.history[self.position] {
+            Some(spanned) => {
+                self.current_token = Some(match &mut spanned.value {
+                    Token::Identifier(name) if !recording => Token::Identifier(std::mem::take(name)),
+                    Token::String(text) if !recording => Token::String(std::mem::take(text)),
+                    token => token.clone(),
+                });
+                self.current_span = Some(spanned.range());
+            }
+            None => {
+                self.current_token = None;
+                self.current_span = None;
+            }
+        }
+    }
+
+    /// Puts the text that `load_current` moved out back into the history, if no caller took the current token
+    fn stash_current(&mut self) {
+        if let (Some(token @ (Token::Identifier(_) | Token::String(_))), Some(Some(spanned))) =
+            (self.current_token.take(), self.history.get_mut(self.position))
+        {
+            spanned.value = token;
+        }
+    }
+
+    /// Drops the history of the statements before the current token, which nothing rewinds to anymore
+    /// The token just before the current one is kept for `previous_end`. Checkpoints taken before this can no
+    /// longer be restored, and tokens are moved rather than copied again until the next checkpoint.
+    fn trim_history(&mut self) {
+        let dropped = self.position.saturating_sub(1);
+        self.history.drain(..dropped);
+        self.position -= dropped;
+        self.recording = false;
+    }
+
+    /// Takes the current token like `current_token.take()`, for places that expect a name
+    /// A non-reserved keyword is handed back as the identifier it spells, in lowercase since the keyword
+    /// token does not keep the original spelling.
+    fn take_name_token(&mut self) -> Option<Token> {
+        match self.current_token.take() {
+            Some(Token::Keyword(keyword)) if !keyword.is_reserved() => Some(Token::Identifier(keyword.to_string().to_lowercase())),
+            other => other,
+        }
+    }
+
+    /// Consumes the current token if it is an identifier and hands back its name
+    /// Any other token is left in place, for optional names such as a bare alias.
+    fn take_identifier(&mut self) -> Option<String> {
+        match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                Some(name)
+            }
+            other => {
+                self.current_token = other;
+                None
+            }
+        }
+    }
+
+    fn peek_token(&mut self) -> Option<&Token> {
+        match self.history.get(self.position + 1) {
+            Some(spanned) => spanned.as_ref().map(|spanned| &spanned.value),
+            None => self.tokens.peek().and_then(|result| result.as_ref().ok().map(|spanned| &spanned.value)),
+        }
+    }
+
+    /// Byte range of the input that the current token was read from
+    /// This lets error reporting point at the exact offending token.
+    pub fn current_span(&self) -> Option<Range<usize>> {
+        self.current_span.clone()
+    }
+
+    /// Saves the current position so that a speculative parse can be undone with `restore`
+    /// From here on tokens are copied into the history, since the parse may take them before it is undone.
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        if let (Some(token), Some(Some(spanned))) = (&self.current_token, self.history.get_mut(self.position)) {
+            spanned.value = token.clone();
+        }
+        self.recording = true;
+        self.mark()
+    }
+
+    /// Like `checkpoint`, for rewinding over tokens that are not taken in between, or when only the kind and
+    /// span of the tokens matter after rewinding
+    fn mark(&self) -> Checkpoint {
+        Checkpoint { position: self.position }
+    }
+
+    /// Rewinds the parser to a previously saved checkpoint
+    /// Tokens consumed since the checkpoint will be handed out again by subsequent parsing.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.stash_current();
+        self.position = checkpoint.position;
+        self.load_current();
+    }
+
+    fn expect_token(&mut self, expected: Token) -> Result<(), ParseError> {
+        match &self.current_token {
+            Some(token) if *token == expected => {
+                self.advance();
+                Ok(())
+            }
+            Some(token) => Err(ParseError::unexpected(format!("{:?}", expected), token)),
+            None => Err(ParseError::UnexpectedEof { context: None }),
+        }
+    }
+
+    fn expect_keyword(&mut self, expected: Keyword) -> Result<(), ParseError> {
+        match &self.current_token {
+            Some(Token::Keyword(keyword)) if *keyword == expected => {
+                self.advance();
+                Ok(())
+            }
+            Some(token) => Err(ParseError::unexpected(format!("keyword {:?}", expected), token)),
+            None => Err(ParseError::UnexpectedEof { context: None }),
+        }
+    }
+
+    /// Consumes `keywords` if the upcoming tokens are exactly that sequence, like `IS NOT NULL`
+    /// Otherwise the parser is rewound to where it started, so the caller can try another sequence.
+    fn try_consume_keywords(&mut self, keywords: &[Keyword]) -> bool {
+        let checkpoint = self.mark();
+        for keyword in keywords {
+            match &self.current_token {
+                Some(Token::Keyword(current)) if current == keyword => {
+                    self.advance();
+                }
+                _ => {
+                    self.restore(checkpoint);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn get_precedence(&self, token: &Token) -> Precedence {
+        match token {
+            // MySQL reads `||` as a synonym for OR rather than as concatenation
+            Token::Concat if self.dialect == Dialect::MySql => Precedence::Or,
+            Token::Plus | Token::Minus | Token::Concat => Precedence::Term,
+            Token::Multiply | Token::Divide | Token::Modulo => Precedence::Factor,
+            Token::Equal | Token::NotEqual | Token::NullSafeEqual => Precedence::Equality,
+            Token::GreaterThan | Token::GreaterThanOrEqual |
+            Token::LessThan | Token::LessThanOrEqual => Precedence::Compare,
+            Token::Keyword(Keyword::Like | Keyword::In | Keyword::Between | Keyword::Is) => Precedence::Compare,
+            Token::Keyword(Keyword::And) => Precedence::And,
+            Token::Keyword(Keyword::Or) => Precedence::Or,
+            _ => Precedence::None,
+        }
+    }
+
+    /// Precedence of the current token as an infix operator
+    /// `NOT` only continues an expression when it negates a following infix keyword (`NOT LIKE`, `NOT IN`, `NOT BETWEEN`),
+    /// which needs one token of lookahead.
+    fn current_precedence(&mut self) -> Precedence {
+        match &self.current_token {
+            Some(Token::Keyword(Keyword::Not)) => match self.peek_token() {
+                Some(Token::Keyword(Keyword::Like | Keyword::In | Keyword::Between)) => Precedence::Compare,
+                _ => Precedence::None,
+            },
+            Some(token) => self.get_precedence(token),
+            None => Precedence::None,
+        }
+    }
+
+    /// Parses statements until the end of input, pairing each result with the byte range of its source text
+    /// A statement that fails to parse is skipped up to and including its semicolon, so one error does not
+    /// hide the statements after it.
+    pub fn parse_statements(&mut self) -> Vec<(Result<Statement, ParseError>, Range<usize>)> {
+        let mut statements = Vec::new();
+        while let Some(span) = self.current_span() {
+            if let Some(Token::Eof) = self.current_token {
+                break;
+            }
+            self.trim_history();
+
+            let result = self.parse_statement();
+            if result.is_err() {
+                self.skip_past_semicolon();
+            }
+            let mut end = self.previous_end();
+            // The character the tokenizer failed on never became a token, but the span should still cover it
+            if let Err(ParseError::LexError(error)) = &result {
+                end = end.max(error.position + 1);
+            }
+            statements.push((result, span.start..end));
+        }
+        // A tokenizer error right after a complete statement stops the loop before it is reported
+        if let Some(error) = self.lex_error.take() {
+            let position = error.position;
+            statements.push((Err(ParseError::LexError(error)), position..position + 1));
+        }
+        statements
+    }
+
+    /// Skips tokens up to and including the next semicolon, or to the end of input
+    fn skip_past_semicolon(&mut self) {
+        // A failed parse may have taken the current token, but the history still has its kind
+        if self.current_token.is_none() {
+            self.load_current();
+        }
+        while let Some(token) = &self.current_token {
+            match token {
+                Token::Eof => break,
+                Token::Semicolon => {
+                    self.advance();
+                    break;
+                }
+                _ => {
+                    self.advance();
+                }
+            }
+        }
+    }
+
+    /// Byte offset where the token before the current one ends
+    fn previous_end(&self) -> usize {
+        self.history[..self.position]
+            .iter()
+            .rev()
+            .find_map(|spanned| spanned.as_ref().map(|spanned| spanned.end))
+            .unwrap_or(0)
+    }
+
+    /// Parses one statement, up to and including its semicolon
+    /// When the tokenizer failed along the way, its error is returned instead of the parse error it
+    /// caused, since the parser only sees the token stream end early.
+    pub fn parse_statement(&mut self) -> Result<Statement, ParseError> {
+        self.parse_terminated_statement(true, false)
+    }
+
+    /// Parses a statement that makes up the whole input, like the argument of `sql_parser::parse`
+    /// The closing semicolon may be left out unless `semicolon_required` is set, but nothing may follow it.
+    pub fn parse_only_statement(&mut self, semicolon_required: bool) -> Result<Statement, ParseError> {
+        self.parse_terminated_statement(semicolon_required, true)
+    }
+
+    fn parse_terminated_statement(&mut self, semicolon_required: bool, whole_input: bool) -> Result<Statement, ParseError> {
+        let start = self.mark();
+        let result = match &self.current_token {
+            Some(Token::Keyword(Keyword::Select)) => self.parse_select(),
+            Some(Token::Keyword(Keyword::Create)) => self.parse_create_table(),
+            Some(Token::Keyword(Keyword::Update)) => self.parse_update(),
+            Some(Token::Keyword(Keyword::Drop)) => self.parse_drop_table(),
+            Some(Token::Keyword(Keyword::Alter)) => self.parse_alter_table(),
+            Some(Token::Keyword(Keyword::Insert)) => self.parse_insert(),
+            Some(Token::Keyword(Keyword::Fetch)) | Some(Token::Keyword(Keyword::Move)) => self.parse_cursor_operation(),
+            Some(token) => Err(ParseError::unexpected("SELECT, CREATE, UPDATE, DROP, ALTER, INSERT, FETCH or MOVE", token)),
+            None => Err(ParseError::UnexpectedEof { context: None }),
+        };
+        let result = result.and_then(|statement| self.finish_statement(statement, semicolon_required, whole_input));
+        result.map_err(|error| match self.lex_error.take() {
+            Some(lex_error) => ParseError::LexError(lex_error),
+            None => self.find_unbalanced_parenthesis(start).unwrap_or(error),
+        })
+    }
+
+    /// Consumes the semicolon after a statement and, when the statement is the whole input, checks that nothing follows
+    fn finish_statement(&mut self, statement: Statement, semicolon_required: bool, whole_input: bool) -> Result<Statement, ParseError> {
+        let at_end = matches!(self.current_token, Some(Token::Eof));
+        if semicolon_required || !at_end {
+            self.expect_token(Token::Semicolon)?;
+        }
+        if whole_input {
+            match &self.current_token {
+                Some(Token::Eof) => {}
+                Some(token) => return Err(ParseError::unexpected("end of input after the statement", token)),
+                None => return Err(ParseError::UnexpectedEof { context: None }),
+            }
+        }
+        Ok(statement)
+    }
+
+    /// Looks for a parenthesis without a partner in the statement that starts at `start`
+    /// A missing `)` usually makes parsing fail far from the `(` it belongs to, so a failed statement is
+    /// rescanned up to its semicolon and the parser is left where the parse failed.
+    fn find_unbalanced_parenthesis(&mut self, start: Checkpoint) -> Option<ParseError> {
+        let failed_at = self.mark();
+        self.restore(start);
+
+        let mut open = Vec::new();
+        let mut unmatched_close = None;
+        while let (Some(token), Some(span)) = (&self.current_token, &self.current_span) {
+            match token {
+                Token::Eof | Token::Semicolon => break,
+                Token::LeftParentheses => open.push(span.start),
+                Token::RightParentheses => match open.pop() {
+                    Some(_) => {}
+                    None => {
+                        unmatched_close = Some(span.start);
+                        break;
+                    }
+                },
+                _ => {}
+            }
+            self.advance();
+        }
+
+        self.restore(failed_at);
+        unmatched_close
+            .or(open.last().copied())
+            .map(|position| ParseError::UnbalancedParentheses { position })
+    }
+
+    /// Parses a SELECT statement, along with any `UNION [ALL] SELECT ...` that follows it
+    /// Like the other statement parsers, it stops before the semicolon, which `parse_statement` consumes.
+    /// The operands of a chain of unions are grouped from the left, and only the last one may carry
+    /// ORDER BY, LIMIT or OFFSET, since those apply to the combined result.
+    fn parse_select(&mut self) -> Result<Statement, ParseError> {
+        let mut statement = self.parse_select_body()?;
+        let has_result_clauses = |statement: &Statement| {
+            matches!(statement, Statement::Select { orderby, limit, offset, .. }
+                if !orderby.is_empty() || limit.is_some() || offset.is_some())
+        };
+        let mut last_has_result_clauses = has_result_clauses(&statement);
+
+        while let Some(Token::Keyword(Keyword::Union)) = self.current_token {
+            if last_has_result_clauses {
+                return Err(ParseError::Invalid("ORDER BY, LIMIT and OFFSET can only follow the last SELECT of a UNION".to_string()));
+            }
+            self.advance();
+
+            let mut all = false;
+            if let Some(Token::Keyword(Keyword::All)) = self.current_token {
+                self.advance();
+                all = true;
+            }
+
+            match self.current_token {
+                Some(Token::Keyword(Keyword::Select)) => {}
+                Some(ref token) => return Err(ParseError::unexpected("SELECT after UNION", token)),
+                None => return Err(ParseError::eof("after UNION")),
+            }
+            let right = self.parse_select_body()?;
+            last_has_result_clauses = has_result_clauses(&right);
+            statement = Statement::SetOperation {
+                left: Box::new(statement),
+                op: SetOp::Union,
+                all,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(statement)
+    }
+
+    /// Parses the row count of a LIMIT or OFFSET clause
+    /// Only a number is accepted, unless the dialect allows any expression there. Either way a negated number
+    /// like `-5` can never be a row count and is rejected.
+    fn parse_row_count(&mut self, clause: &str) -> Result<Expression, ParseError> {
+        let expr = self.parse_expression()?;
+        match &expr {
+            Expression::UnaryOperation { operand, operator: UnaryOperator::Minus }
+                if matches!(**operand, Expression::Number(_) | Expression::Float(_)) =>
+            {
+                Err(ParseError::Invalid(format!("{} must be non-negative", clause)))
+            }
+            Expression::Number(_) => Ok(expr),
+            _ if self.dialect.supports_limit_expressions() => Ok(expr),
+            _ => Err(ParseError::Invalid(format!(
+                "{} must be a number, expressions are not supported in the {} dialect",
+                clause,
+                self.dialect.name()
+            ))),
+        }
+    }
+
+    /// Parses a single SELECT up to, but not including, its semicolon
+    fn parse_select_body(&mut self) -> Result<Statement, ParseError> {
+        self.advance(); // Skip SELECT
+
+        let mut distinct = false;
+        if let Some(Token::Keyword(Keyword::Distinct)) = self.current_token {
+            self.advance();
+            distinct = true;
+        }
+
+        // Parse columns
+        let columns = self.parse_projection()?;
+
+        // A lone `*` may leave out some columns, `* EXCEPT (a, b)`, and replace others, `* REPLACE (a + 1 AS a)`
+        let mut wildcard_except = Vec::new();
+        let mut wildcard_replace = Vec::new();
+        if let Some(Token::Keyword(keyword @ (Keyword::Except | Keyword::Replace))) = &self.current_token {
+            let modifier = if *keyword == Keyword::Except { "EXCEPT" } else { "REPLACE" };
+            if columns != [Expression::Identifier("*".to_string())] {
+                return Err(ParseError::Invalid(format!("{} column list can only follow *", modifier)));
+            }
+            if !self.dialect.supports_wildcard_modifiers() {
+                return Err(ParseError::Invalid(format!("* {} is not supported in the {} dialect", modifier, self.dialect.name())));
+            }
+        }
+        if let Some(Token::Keyword(Keyword::Except)) = self.current_token {
+            self.advance();
+            wildcard_except = self.parse_column_list()?;
+        }
+        if let Some(Token::Keyword(Keyword::Replace)) = self.current_token {
+            self.advance();
+            wildcard_replace = self.parse_wildcard_replace()?;
+        }
+
+        match self.current_token {
+            Some(Token::Keyword(Keyword::From)) => {}
+            Some(ref token) => return Err(ParseError::unexpected("FROM or comma", token)),
+            None => return Err(ParseError::UnexpectedEof { context: None }),
+        }
+
+        // Parse FROM clause
+        self.expect_keyword(Keyword::From)?;
+        let from = self.parse_table_ref()?;
+
+        // Parse any number of JOIN clauses
+        let mut joins = Vec::new();
+        while let Some(join_type) = self.parse_join_type()? {
+            let table = self.parse_table_ref()?;
+            self.expect_keyword(Keyword::On)
+                .map_err(|e| e.expecting(format!("ON after JOIN {}", table.name)))?;
+            let on = self.parse_expression()?;
+            joins.push(Join { table, join_type, on });
+        }
+
+        // Parse optional WHERE clause
+        let mut where_clause = None;
+        if let Some(Token::Keyword(Keyword::Where)) = self.current_token {
+            self.advance();
+            where_clause = Some(self.parse_expression()?);
+        }
+
+        // Parse optional GROUP BY clause
+        let mut groupby = Vec::new();
+        if let Some(Token::Keyword(Keyword::Group)) = self.current_token {
+            self.advance();
+            self.expect_keyword(Keyword::By)?;
+
+            loop {
+                groupby.push(self.parse_expression()?);
+
+                match self.current_token {
+                    Some(Token::Comma) => {
+                        self.advance();
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        // Parse optional HAVING clause, which filters the groups made by GROUP BY
+        let mut having = None;
+        if let Some(Token::Keyword(Keyword::Having)) = self.current_token {
+            if groupby.is_empty() {
+                return Err(ParseError::Invalid("HAVING requires a GROUP BY clause".to_string()));
+            }
+            self.advance();
+            having = Some(self.parse_expression()?);
+        }
+
+        // Parse optional WINDOW clause: WINDOW name AS (spec) [, name AS (spec) ...]
+        let mut windows = Vec::new();
+        if let Some(Token::Keyword(Keyword::Window)) = self.current_token {
+            self.advance();
+
+            loop {
+                let name = match self.take_name_token() {
+                    Some(Token::Identifier(name)) => {
+                        self.advance();
+                        name
+                    }
+                    Some(token) => return Err(ParseError::unexpected("window name", &token)),
+                    None => return Err(ParseError::eof("while parsing WINDOW clause")),
+                };
+                self.expect_keyword(Keyword::As)?;
+                windows.push((name, self.parse_window_spec()?));
+
+                match self.current_token {
+                    Some(Token::Comma) => {
+                        self.advance();
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        // Parse optional ORDER BY clause
+        let mut orderby = Vec::new();
+        if let Some(Token::Keyword(Keyword::Order)) = self.current_token {
+            self.advance();
+            self.expect_keyword(Keyword::By)?;
+
+            loop {
+                orderby.push(self.parse_order_by_item()?);
+                
+                match self.current_token {
+                    Some(Token::Comma) => {
+                        self.advance();
+                        continue;
+                    }
+                    // A subquery's ORDER BY ends at the closing parenthesis
+                    Some(Token::Semicolon | Token::Eof | Token::RightParentheses | Token::Keyword(Keyword::Limit | Keyword::Offset | Keyword::Union)) | None => break,
+                    Some(ref token) => return Err(ParseError::unexpected("semicolon or comma", token)),
+                }
+            }
+        }
+
+        // Parse optional LIMIT and OFFSET clauses, in that order
+        let mut limit = None;
+        if let Some(Token::Keyword(Keyword::Limit)) = self.current_token {
+            self.advance();
+            limit = Some(self.parse_row_count("LIMIT")?);
+        }
+        let mut offset = None;
+        if let Some(Token::Keyword(Keyword::Offset)) = self.current_token {
+            self.advance();
+            offset = Some(self.parse_row_count("OFFSET")?);
+        }
+        if let Some(Token::Keyword(Keyword::Limit)) = self.current_token {
+            return Err(ParseError::Invalid(if offset.is_some() {
+                "LIMIT must come before OFFSET".to_string()
+            } else {
+                "LIMIT specified more than once".to_string()
+            }));
+        }
+
+        Ok(Statement::Select {
+            distinct,
+            columns,
+            wildcard_except,
+            wildcard_replace,
+            from,
+            joins,
+            r#where: where_clause,
+            groupby,
+            having,
+            windows,
+            orderby,
+            limit,
+            offset,
+        })
+    }
+
+    /// Consumes the keywords that start a join clause and returns its type, or `None` if no join follows
+    fn parse_join_type(&mut self) -> Result<Option<JoinType>, ParseError> {
+        let join_type = match self.current_token {
+            Some(Token::Keyword(Keyword::Join)) => {
+                self.advance();
+                return Ok(Some(JoinType::Inner));
+            }
+            Some(Token::Keyword(Keyword::Inner)) => JoinType::Inner,
+            Some(Token::Keyword(Keyword::Left)) => JoinType::Left,
+            Some(Token::Keyword(Keyword::Right)) => JoinType::Right,
+            Some(Token::Keyword(Keyword::Full)) => JoinType::Full,
+            _ => return Ok(None),
+        };
+        self.advance();
+        // OUTER is optional noise after LEFT, RIGHT and FULL
+        if join_type != JoinType::Inner {
+            if let Some(Token::Keyword(Keyword::Outer)) = self.current_token {
+                self.advance();
+            }
+        }
+        self.expect_keyword(Keyword::Join)?;
+        Ok(Some(join_type))
+    }
+
+    /// Parses a table name with an optional `[AS] alias`
+    fn parse_table_ref(&mut self) -> Result<TableRef, ParseError> {
+        let name = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("table name", &token)),
+            None => return Err(ParseError::eof("while parsing table name")),
+        };
+
+        if let Some(Token::Keyword(Keyword::As)) = self.current_token {
+            self.advance();
+            return match self.take_name_token() {
+                Some(Token::Identifier(alias)) => {
+                    self.advance();
+                    Ok(TableRef { name, alias: Some(alias) })
+                }
+                Some(token) => Err(ParseError::unexpected("alias after AS", &token)),
+                None => Err(ParseError::eof("after AS")),
+            };
+        }
+        let alias = self.take_identifier();
+        Ok(TableRef { name, alias })
+    }
+
+    /// Parses a projection list, as used by SELECT and RETURNING
+    /// The list is either a lone `*` or comma-separated expressions, each with an optional `[AS] alias`.
+    fn parse_projection(&mut self) -> Result<Vec<Expression>, ParseError> {
+        // Handle the * case
+        if let Some(Token::Multiply) = self.current_token {
+            self.advance();
+            return Ok(vec![Expression::Identifier("*".to_string())]);
+        }
+
+        let mut items = Vec::new();
+        loop {
+            let expr = self.parse_expression()?;
+            items.push(self.parse_optional_alias(expr)?);
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                _ => break,
+            }
+        }
+        Ok(items)
+    }
+
+    /// Parses the parenthesized `expr AS column` list of a `* REPLACE (...)` projection
+    fn parse_wildcard_replace(&mut self) -> Result<Vec<(Expression, String)>, ParseError> {
+        self.expect_token(Token::LeftParentheses)?;
+
+        let mut replacements = Vec::new();
+        loop {
+            let expr = self.parse_expression()?;
+            self.expect_keyword(Keyword::As)?;
+            match self.take_name_token() {
+                Some(Token::Identifier(column)) => {
+                    self.advance();
+                    replacements.push((expr, column));
+                }
+                Some(token) => return Err(ParseError::unexpected("column name after AS", &token)),
+                None => return Err(ParseError::eof("after AS")),
+            }
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                Some(Token::RightParentheses) => {
+                    self.advance();
+                    break;
+                }
+                Some(ref token) => return Err(ParseError::unexpected("comma or closing parenthesis", token)),
+                None => return Err(ParseError::UnexpectedEof { context: None }),
+            }
+        }
+        Ok(replacements)
+    }
+
+    /// Wraps `expr` in `Expression::Aliased` when it is followed by `AS alias`, or by a bare `alias`
+    fn parse_optional_alias(&mut self, expr: Expression) -> Result<Expression, ParseError> {
+        // Without AS, an identifier right after the expression can only be an alias: `price * quantity total`
+        if let Some(alias) = self.take_identifier() {
+            return Ok(Expression::Aliased {
+                expr: Box::new(expr),
+                alias,
+            });
+        }
+
+        if let Some(Token::Keyword(Keyword::As)) = self.current_token {
+            self.advance();
+            return match self.take_name_token() {
+                Some(Token::Identifier(alias)) => {
+                    self.advance();
+                    Ok(Expression::Aliased {
+                        expr: Box::new(expr),
+                        alias,
+                    })
+                }
+                Some(token) => Err(ParseError::unexpected("alias after AS", &token)),
+                None => Err(ParseError::eof("after AS")),
+            };
+        }
+        Ok(expr)
+    }
+
+    fn parse_insert(&mut self) -> Result<Statement, ParseError> {
+        self.advance(); // Skip INSERT
+        self.expect_keyword(Keyword::Into)?;
+
+        // Parse table name
+        let table_name = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("table name", &token)),
+            None => return Err(ParseError::UnexpectedEof { context: None }),
+        };
+
+        // Parse optional column list
+        let mut columns = Vec::new();
+        if let Some(Token::LeftParentheses) = self.current_token {
+            columns = self.parse_column_list()?;
+        }
+
+        // MySQL can name each column next to its value instead: INSERT INTO t SET a = 1, b = 2
+        let source = if let Some(Token::Keyword(Keyword::Set)) = self.current_token {
+            if self.dialect != Dialect::MySql {
+                return Err(ParseError::Invalid(format!("INSERT ... SET is not supported in the {} dialect", self.dialect.name())));
+            }
+            if !columns.is_empty() {
+                return Err(ParseError::Invalid("INSERT ... SET cannot have a column list".to_string()));
+            }
+            self.advance();
+            InsertSource::Assignments(self.parse_assignments()?)
+        } else {
+            InsertSource::Values(self.parse_values(&columns)?)
+        };
+
+        // Parse optional RETURNING clause
+        let mut returning = Vec::new();
+        if let Some(Token::Keyword(Keyword::Returning)) = self.current_token {
+            self.advance();
+            returning = self.parse_projection()?;
+        }
+
+        Ok(Statement::Insert {
+            table_name,
+            columns,
+            source,
+            returning,
+        })
+    }
+
+    /// Parses the rows of a VALUES clause, starting at the VALUES keyword
+    fn parse_values(&mut self, columns: &[String]) -> Result<Vec<Vec<Expression>>, ParseError> {
+        self.expect_keyword(Keyword::Values)?;
+        let mut values = Vec::new();
+        loop {
+            self.expect_token(Token::LeftParentheses)?;
+            let mut row = Vec::new();
+            loop {
+                row.push(self.parse_expression()?);
+
+                match self.current_token {
+                    Some(Token::Comma) => {
+                        self.advance();
+                        continue;
+                    }
+                    Some(Token::RightParentheses) => {
+                        self.advance();
+                        break;
+                    }
+                    Some(ref token) => return Err(ParseError::unexpected("comma or closing parenthesis", token)),
+                    None => return Err(ParseError::UnexpectedEof { context: None }),
+                }
+            }
+
+            // Every row must match the explicit column list, when there is one
+            if !columns.is_empty() && row.len() != columns.len() {
+                return Err(ParseError::Invalid(format!("INSERT has {} columns but {} values", columns.len(), row.len())));
+            }
+            values.push(row);
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                _ => break,
+            }
+        }
+        Ok(values)
+    }
+
+    /// Parses a parenthesized, comma-separated list of column names, such as `(a, b, c)`
+    fn parse_column_list(&mut self) -> Result<Vec<String>, ParseError> {
+        self.expect_token(Token::LeftParentheses)?;
+
+        let mut columns = Vec::new();
+        loop {
+            match self.take_name_token() {
+                Some(Token::Identifier(name)) => {
+                    self.advance();
+                    columns.push(name);
+                }
+                Some(token) => return Err(ParseError::unexpected("column name", &token)),
+                None => return Err(ParseError::eof("while parsing column list")),
+            }
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                Some(Token::RightParentheses) => {
+                    self.advance();
+                    break;
+                }
+                Some(ref token) => return Err(ParseError::unexpected("comma or closing parenthesis", token)),
+                None => return Err(ParseError::UnexpectedEof { context: None }),
+            }
+        }
+        Ok(columns)
+    }
+
+    fn parse_create_table(&mut self) -> Result<Statement, ParseError> {
+        self.advance(); // Skip CREATE
+
+        // TEMP is a common abbreviation of TEMPORARY
+        let mut temporary = false;
+        if let Some(Token::Keyword(Keyword::Temporary | Keyword::Temp)) = self.current_token {
+            self.advance();
+            temporary = true;
+        }
+        self.expect_keyword(Keyword::Table)?;
+
+        // Parse table name
+        let table_name = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("table name", &token)),
+            None => return Err(ParseError::UnexpectedEof { context: None }),
+        };
+
+        // Expect opening parenthesis
+        self.expect_token(Token::LeftParentheses)?;
+
+        // Parse column definitions and table constraints
+        let mut column_list = Vec::new();
+        let mut table_constraints = Vec::new();
+        loop {
+            match self.current_token {
+                Some(Token::Keyword(Keyword::Primary | Keyword::Foreign | Keyword::Constraint)) => {
+                    table_constraints.push(self.parse_table_constraint()?)
+                }
+                _ => column_list.push(self.parse_column_definition()?),
+            }
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                Some(Token::RightParentheses) => break,
+                Some(ref token) => return Err(ParseError::unexpected("comma or closing parenthesis", token)),
+                None => return Err(ParseError::UnexpectedEof { context: None }),
+            }
+        }
+
+        // Expect closing parenthesis
+        self.expect_token(Token::RightParentheses)?;
+
+        let primary_keys = table_constraints.iter().filter(|constraint| matches!(constraint, TableConstraint::PrimaryKey { .. })).count()
+            + column_list.iter().filter(|column| column.constraints.contains(&Constraint::PrimaryKey)).count();
+        if primary_keys > 1 {
+            return Err(ParseError::Invalid(format!("Table {} has more than one PRIMARY KEY", table_name)));
+        }
+
+        // Parse MySQL table options, such as ENGINE=InnoDB
+        let mut options = Vec::new();
+        while let Some(name) = self.take_identifier() {
+            if self.dialect != Dialect::MySql {
+                return Err(ParseError::Invalid(format!("Table options are not supported in the {} dialect", self.dialect.name())));
+            }
+
+            // The '=' between an option and its value is optional
+            if let Some(Token::Equal) = self.current_token {
+                self.advance();
+            }
+            let value = match self.take_name_token() {
+                Some(Token::Identifier(value) | Token::String(value)) => value,
+                Some(Token::Number(value)) => value.to_string(),
+                Some(token) => return Err(ParseError::unexpected(format!("value for table option {}", name), &token)),
+                None => return Err(ParseError::eof(format!("after table option {}", name))),
+            };
+            self.advance();
+            options.push((name, value));
+
+            // Options may be separated by commas
+            if let Some(Token::Comma) = self.current_token {
+                self.advance();
+            }
+        }
+
+        Ok(Statement::CreateTable {
+            table_name,
+            temporary,
+            column_list,
+            table_constraints,
+            options,
+        })
+    }
+
+    /// Parses a table-level constraint, such as `PRIMARY KEY (a, b)` or `CONSTRAINT fk FOREIGN KEY (a) REFERENCES b(id)`
+    fn parse_table_constraint(&mut self) -> Result<TableConstraint, ParseError> {
+        // Parse optional CONSTRAINT name
+        let mut name = None;
+        if let Some(Token::Keyword(Keyword::Constraint)) = self.current_token {
+            self.advance();
+            match self.take_name_token() {
+                Some(Token::Identifier(constraint_name)) => {
+                    self.advance();
+                    name = Some(constraint_name);
+                }
+                Some(token) => return Err(ParseError::unexpected("constraint name after CONSTRAINT", &token)),
+                None => return Err(ParseError::eof("after CONSTRAINT")),
+            }
+        }
+
+        match self.current_token {
+            Some(Token::Keyword(Keyword::Primary)) => {
+                self.advance();
+                self.expect_keyword(Keyword::Key).map_err(|error| error.expecting("KEY after PRIMARY"))?;
+                let columns = self.parse_column_name_list("PRIMARY KEY")?;
+                Ok(TableConstraint::PrimaryKey { name, columns })
+            }
+            Some(Token::Keyword(Keyword::Foreign)) => {
+                self.advance();
+                self.expect_keyword(Keyword::Key).map_err(|error| error.expecting("KEY after FOREIGN"))?;
+                let columns = self.parse_column_name_list("FOREIGN KEY")?;
+                self.expect_keyword(Keyword::References)
+                    .map_err(|error| error.expecting("REFERENCES after FOREIGN KEY columns"))?;
+
+                let table = match self.take_name_token() {
+                    Some(Token::Identifier(table)) => {
+                        self.advance();
+                        table
+                    }
+                    Some(token) => return Err(ParseError::unexpected("table name after REFERENCES", &token)),
+                    None => return Err(ParseError::eof("after REFERENCES")),
+                };
+
+                // Without a column list the foreign key refers to the primary key of the table
+                let mut referenced_columns = Vec::new();
+                if let Some(Token::LeftParentheses) = self.current_token {
+                    referenced_columns = self.parse_column_name_list("REFERENCES")?;
+                    if referenced_columns.len() != columns.len() {
+                        return Err(ParseError::Invalid(format!(
+                            "FOREIGN KEY has {} columns but references {}",
+                            columns.len(),
+                            referenced_columns.len()
+                        )));
+                    }
+                }
+
+                let (on_delete, on_update, match_type) = self.parse_reference_clauses()?;
+                Ok(TableConstraint::ForeignKey {
+                    name,
+                    columns,
+                    table,
+                    referenced_columns,
+                    on_delete,
+                    on_update,
+                    match_type,
+                })
+            }
+            Some(ref token) => Err(ParseError::unexpected("PRIMARY KEY or FOREIGN KEY", token)),
+            None => Err(ParseError::eof("while parsing table constraint")),
+        }
+    }
+
+    /// Parses a parenthesized, non-empty list of column names, like `(a, b)`
+    fn parse_column_name_list(&mut self, after: &str) -> Result<Vec<String>, ParseError> {
+        self.expect_token(Token::LeftParentheses)
+            .map_err(|error| error.expecting(format!("column list after {}", after)))?;
+        let mut columns = Vec::new();
+        loop {
+            match self.take_name_token() {
+                Some(Token::Identifier(name)) => {
+                    self.advance();
+                    columns.push(name);
+                }
+                Some(token) => return Err(ParseError::unexpected(format!("column name in {} list", after), &token)),
+                None => return Err(ParseError::eof(format!("in {} list", after))),
+            }
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                _ => break,
+            }
+        }
+        self.expect_token(Token::RightParentheses)?;
+        Ok(columns)
+    }
+
+    /// Parses the comma-separated `column = expression` list after SET, as in UPDATE or MySQL's INSERT ... SET
+    fn parse_assignments(&mut self) -> Result<Vec<(String, Expression)>, ParseError> {
+        let mut assignments = Vec::new();
+        loop {
+            let column = match self.take_name_token() {
+                Some(Token::Identifier(name)) => {
+                    self.advance();
+                    name
+                }
+                Some(token) => return Err(ParseError::unexpected("column name in SET clause", &token)),
+                None => return Err(ParseError::eof("while parsing SET clause")),
+            };
+            self.expect_token(Token::Equal)?;
+            assignments.push((column, self.parse_expression()?));
+
+            match self.current_token {
+                Some(Token::Comma) => self.advance(),
+                _ => return Ok(assignments),
+            }
+        }
+    }
+
+    fn parse_update(&mut self) -> Result<Statement, ParseError> {
+        self.advance(); // Skip UPDATE
+
+        // Parse table name
+        let table_name = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("table name", &token)),
+            None => return Err(ParseError::UnexpectedEof { context: None }),
+        };
+
+        self.expect_keyword(Keyword::Set)?;
+        let assignments = self.parse_assignments()?;
+        match self.current_token {
+            Some(Token::Keyword(Keyword::Where)) | Some(Token::Semicolon) | Some(Token::Eof) => {}
+            Some(ref token) => return Err(ParseError::unexpected("WHERE, semicolon or comma", token)),
+            None => return Err(ParseError::UnexpectedEof { context: None }),
+        }
+
+        // Parse optional WHERE clause, which may also be WHERE CURRENT OF cursor
+        let mut where_clause = None;
+        if let Some(Token::Keyword(Keyword::Where)) = self.current_token {
+            self.advance();
+            where_clause = Some(self.parse_where_clause()?);
+        }
+
+        Ok(Statement::Update {
+            table_name,
+            assignments,
+            r#where: where_clause,
+        })
+    }
+
+    /// Parses what follows WHERE in a statement that supports positioned updates
+    fn parse_where_clause(&mut self) -> Result<WhereClause, ParseError> {
+        if self.try_consume_keywords(&[Keyword::Current, Keyword::Of]) {
+            return match self.take_name_token() {
+                Some(Token::Identifier(cursor)) => {
+                    self.advance();
+                    Ok(WhereClause::CurrentOf(cursor))
+                }
+                Some(token) => Err(ParseError::unexpected("cursor name after CURRENT OF", &token)),
+                None => Err(ParseError::eof("after CURRENT OF")),
+            };
+        }
+        Ok(WhereClause::Expression(self.parse_expression()?))
+    }
+
+    fn parse_drop_table(&mut self) -> Result<Statement, ParseError> {
+        self.advance(); // Skip DROP
+        self.expect_keyword(Keyword::Table)?;
+
+        // Parse optional IF EXISTS
+        let mut if_exists = false;
+        if let Some(Token::Keyword(Keyword::If)) = self.current_token {
+            self.advance();
+            self.expect_keyword(Keyword::Exists)?;
+            if_exists = true;
+        }
+
+        // Parse the comma-separated table names
+        let mut table_names = Vec::new();
+        loop {
+            match self.take_name_token() {
+                Some(Token::Identifier(name)) => {
+                    self.advance();
+                    table_names.push(name);
+                }
+                Some(token) => return Err(ParseError::unexpected("table name after DROP TABLE", &token)),
+                None => return Err(ParseError::eof("after DROP TABLE")),
+            }
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                _ => break,
+            }
+        }
+
+        // Parse optional CASCADE or RESTRICT
+        let drop_behavior = match self.current_token {
+            Some(Token::Keyword(Keyword::Cascade)) => Some(DropBehavior::Cascade),
+            Some(Token::Keyword(Keyword::Restrict)) => Some(DropBehavior::Restrict),
+            _ => None,
+        };
+        if drop_behavior.is_some() {
+            self.advance();
+        }
+
+        Ok(Statement::DropTable {
+            table_names,
+            if_exists,
+            drop_behavior,
+        })
+    }
+
+    /// Parses `FETCH` or `MOVE`, which share the `[direction] [FROM | IN] cursor` syntax
+    fn parse_cursor_operation(&mut self) -> Result<Statement, ParseError> {
+        let is_fetch = matches!(self.current_token, Some(Token::Keyword(Keyword::Fetch)));
+        let statement_name = if is_fetch { "FETCH" } else { "MOVE" };
+        self.advance(); // Skip FETCH or MOVE
+
+        // Parse the optional direction. Apart from ALL, the direction words are not keywords, so that they
+        // stay usable as names elsewhere, and are recognised here by their spelling.
+        let direction = match &self.current_token {
+            Some(Token::Keyword(Keyword::All)) => Some(FetchDirection::All),
+            Some(Token::Identifier(word)) => match word.to_ascii_uppercase().as_str() {
+                "NEXT" => Some(FetchDirection::Next),
+                "PRIOR" => Some(FetchDirection::Prior),
+                "FIRST" => Some(FetchDirection::First),
+                "LAST" => Some(FetchDirection::Last),
+                "ABSOLUTE" => {
+                    self.advance();
+                    Some(FetchDirection::Absolute(self.parse_fetch_count("ABSOLUTE")?))
+                }
+                "RELATIVE" => {
+                    self.advance();
+                    Some(FetchDirection::Relative(self.parse_fetch_count("RELATIVE")?))
+                }
+                _ => None,
+            },
+            Some(Token::Number(_)) | Some(Token::Minus) => Some(FetchDirection::Count(self.parse_fetch_count(statement_name)?)),
+            _ => None,
+        };
+        if matches!(
+            direction,
+            Some(FetchDirection::Next | FetchDirection::Prior | FetchDirection::First | FetchDirection::Last | FetchDirection::All)
+        ) {
+            self.advance();
+        }
+
+        // Parse optional FROM or IN
+        if let Some(Token::Keyword(Keyword::From)) | Some(Token::Keyword(Keyword::In)) = self.current_token {
+            self.advance();
+        }
+
+        // Parse cursor name
+        let cursor = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected(format!("cursor name after {}", statement_name), &token)),
+            None => return Err(ParseError::eof(format!("after {}", statement_name))),
+        };
+
+        Ok(if is_fetch {
+            Statement::Fetch { direction, cursor }
+        } else {
+            Statement::Move { direction, cursor }
+        })
+    }
+
+    /// Parses the optionally negative row count of a `FETCH` or `MOVE` direction
+    fn parse_fetch_count(&mut self, after: &str) -> Result<i64, ParseError> {
+        let negative = matches!(self.current_token, Some(Token::Minus));
+        if negative {
+            self.advance();
+        }
+        match self.current_token.take() {
+            Some(Token::Number(count)) => {
+                self.advance();
+                let count = i64::try_from(count).map_err(|_| ParseError::Invalid(format!("{} count {} is too large", after, count)))?;
+                Ok(if negative { -count } else { count })
+            }
+            Some(token) => Err(ParseError::unexpected(format!("row count after {}", after), &token)),
+            None => Err(ParseError::eof(format!("after {}", after))),
+        }
+    }
+
+    fn parse_alter_table(&mut self) -> Result<Statement, ParseError> {
+        self.advance(); // Skip ALTER
+        self.expect_keyword(Keyword::Table)?;
+
+        // Parse table name
+        let table_name = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("table name", &token)),
+            None => return Err(ParseError::UnexpectedEof { context: None }),
+        };
+
+        // Parse the action
+        let action = match self.current_token {
+            Some(Token::Keyword(Keyword::Add)) => {
+                self.advance();
+                match self.current_token {
+                    Some(Token::Keyword(Keyword::Primary | Keyword::Foreign | Keyword::Constraint)) => {
+                        AlterAction::AddConstraint(self.parse_table_constraint()?)
+                    }
+                    _ => {
+                        // The COLUMN keyword is optional: ADD [COLUMN] column_definition
+                        if let Some(Token::Keyword(Keyword::Column)) = self.current_token {
+                            self.advance();
+                        }
+                        AlterAction::AddColumn(self.parse_column_definition()?)
+                    }
+                }
+            }
+            Some(Token::Keyword(Keyword::Drop)) => {
+                self.advance();
+                match self.current_token.take() {
+                    Some(Token::Keyword(Keyword::Constraint)) => {
+                        self.advance();
+                        match self.take_name_token() {
+                            Some(Token::Identifier(name)) => {
+                                self.advance();
+                                AlterAction::DropConstraint(name)
+                            }
+                            Some(token) => return Err(ParseError::unexpected("constraint name after DROP CONSTRAINT", &token)),
+                            None => return Err(ParseError::eof("after DROP CONSTRAINT")),
+                        }
+                    }
+                    // MySQL drops the primary key without naming it
+                    Some(Token::Keyword(Keyword::Primary)) => {
+                        if self.dialect != Dialect::MySql {
+                            return Err(ParseError::Invalid(format!("DROP PRIMARY KEY is not supported in the {} dialect", self.dialect.name())));
+                        }
+                        self.advance();
+                        self.expect_keyword(Keyword::Key).map_err(|error| error.expecting("KEY after DROP PRIMARY"))?;
+                        AlterAction::DropPrimaryKey
+                    }
+                    Some(token) => return Err(ParseError::unexpected("CONSTRAINT or PRIMARY KEY after DROP", &token)),
+                    None => return Err(ParseError::eof("after DROP")),
+                }
+            }
+            Some(ref token) => return Err(ParseError::unexpected("ALTER TABLE action (ADD or DROP)", token)),
+            None => return Err(ParseError::eof("while parsing ALTER TABLE action")),
+        };
+
+        Ok(Statement::AlterTable {
+            table_name,
+            action,
+        })
+    }
+
+    fn parse_column_definition(&mut self) -> Result<TableColumn, ParseError> {
+        // Parse column name
+        let column_name = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("column name identifier", &token)),
+            None => return Err(ParseError::eof("while parsing column name")),
+        };
+
+        // Parse column type
+        let mut auto_increment = false;
+        let column_type = match &self.current_token {
+            Some(Token::Keyword(Keyword::Int)) => {
+                self.advance();
+                DBType::Int
+            }
+            Some(Token::Keyword(Keyword::Smallint)) => {
+                self.advance();
+                DBType::SmallInt
+            }
+            Some(Token::Keyword(Keyword::Bigint)) => {
+                self.advance();
+                DBType::BigInt
+            }
+            Some(Token::Keyword(Keyword::Float)) => {
+                self.advance();
+                DBType::Float
+            }
+            Some(Token::Keyword(Keyword::Double)) => {
+                self.advance();
+                DBType::Double
+            }
+            Some(Token::Keyword(Keyword::Date)) => {
+                self.advance();
+                DBType::Date
+            }
+            Some(Token::Keyword(Keyword::Timestamp)) => {
+                self.advance();
+                DBType::Timestamp
+            }
+            Some(Token::Keyword(Keyword::Time)) => {
+                self.advance();
+                DBType::Time
+            }
+            Some(Token::Keyword(Keyword::Decimal)) => {
+                self.advance();
+                self.expect_token(Token::LeftParentheses)
+                    .map_err(|e| e.expecting("'(' after DECIMAL"))?;
+
+                let precision = match &self.current_token {
+                    Some(Token::Number(n)) => {
+                        let precision = *n as usize;
+                        self.advance();
+                        precision
+                    }
+                    Some(token) => return Err(ParseError::unexpected("number for DECIMAL precision", token)),
+                    None => return Err(ParseError::eof("while parsing DECIMAL precision")),
+                };
+
+                // The scale is optional and defaults to 0
+                let mut scale = 0;
+                if let Some(Token::Comma) = self.current_token {
+                    self.advance();
+                    scale = match &self.current_token {
+                        Some(Token::Number(n)) => {
+                            let scale = *n as usize;
+                            self.advance();
+                            scale
+                        }
+                        Some(token) => return Err(ParseError::unexpected("number for DECIMAL scale", token)),
+                        None => return Err(ParseError::eof("while parsing DECIMAL scale")),
+                    };
+                }
+
+                self.expect_token(Token::RightParentheses)
+                    .map_err(|e| e.expecting("')' after DECIMAL precision and scale"))?;
+                if scale > precision {
+                    return Err(ParseError::Invalid(format!("DECIMAL scale {} cannot exceed its precision {}", scale, precision)));
+                }
+                DBType::Decimal { precision, scale }
+            }
+            // PostgreSQL's SERIAL pseudo-types are integers filled from a sequence
+            Some(Token::Keyword(keyword @ (Keyword::Serial | Keyword::Smallserial | Keyword::Bigserial))) => {
+                if self.dialect != Dialect::Postgres {
+                    return Err(ParseError::Invalid(format!(
+                        "{} is not supported in the {} dialect",
+                        keyword.to_string().to_uppercase(),
+                        self.dialect.name()
+                    )));
+                }
+                let column_type = match keyword {
+                    Keyword::Smallserial => DBType::SmallInt,
+                    Keyword::Bigserial => DBType::BigInt,
+                    _ => DBType::Int,
+                };
+                self.advance();
+                auto_increment = true;
+                column_type
+            }
+            // BOOLEAN is the spelling most dialects use for BOOL
+            Some(Token::Keyword(Keyword::Bool | Keyword::Boolean)) => {
+                self.advance();
+                DBType::Bool
+            }
+            Some(Token::Keyword(Keyword::Varchar)) => {
+                self.advance();
+                DBType::Varchar(self.parse_type_length("VARCHAR")?)
+            }
+            Some(Token::Keyword(Keyword::Char)) => {
+                self.advance();
+                DBType::Char(self.parse_type_length("CHAR")?)
+            }
+            Some(Token::Keyword(Keyword::Text)) => {
+                self.advance();
+                DBType::Text
+            }
+            Some(token) => return Err(ParseError::unexpected("column type (INT, SMALLINT, BIGINT, FLOAT, DOUBLE, DECIMAL, BOOL, BOOLEAN, DATE, TIMESTAMP, TIME, VARCHAR, CHAR or TEXT)", token)),
+            None => return Err(ParseError::eof("while parsing column type")),
+        };
+
+        // Parse optional constraints (and, under MySQL, a column comment)
+        let mut constraints = Vec::new();
+        if auto_increment {
+            constraints.push(Constraint::AutoIncrement);
+        }
+        let mut comment = None;
+        loop {
+            // `COMMENT` is not a keyword, so a column can still be called `comment`; it only starts a
+            // comment clause under MySQL and when a string follows it
+            let starts_comment = self.dialect == Dialect::MySql
+                && matches!(&self.current_token, Some(Token::Identifier(word)) if word.eq_ignore_ascii_case("comment"))
+                && matches!(self.peek_token(), Some(Token::String(_)));
+            match &self.current_token {
+                Some(Token::Keyword(Keyword::Primary)) => {
+                    self.advance();
+                    match &self.current_token {
+                        Some(Token::Keyword(Keyword::Key)) => {
+                            self.advance();
+                            constraints.push(Constraint::PrimaryKey);
+                        }
+                        Some(token) => return Err(ParseError::unexpected("KEY after PRIMARY", token)),
+                        None => return Err(ParseError::eof("after PRIMARY")),
+                    }
+                }
+                Some(Token::Keyword(Keyword::Unique)) => {
+                    self.advance();
+                    constraints.push(Constraint::Unique);
+                }
+                Some(Token::Keyword(Keyword::Default)) => {
+                    self.advance();
+                    let expr = self.parse_expression()?;
+                    constraints.push(Constraint::Default(expr));
+                }
+                Some(Token::Keyword(Keyword::Not)) => {
+                    self.advance();
+                    match &self.current_token {
+                        Some(Token::Keyword(Keyword::Null)) => {
+                            self.advance();
+                            constraints.push(Constraint::NotNull);
+                        }
+                        Some(token) => return Err(ParseError::unexpected("NULL after NOT", token)),
+                        None => return Err(ParseError::eof("after NOT")),
+                    }
+                }
+                Some(Token::Keyword(Keyword::Check)) => {
+                    self.advance();
+                    match &self.current_token {
+                        Some(Token::LeftParentheses) => {
+                            self.advance();
+                            let mut expr = self.parse_expression()?;
+                            // A CHECK constraint may only look at the row being checked
+                            let mut has_subquery = false;
+                            expr.map(&mut |node: &mut Expression| {
+                                has_subquery |= matches!(node, Expression::Subquery(_) | Expression::InSubquery { .. } | Expression::Exists { .. });
+                            });
+                            if has_subquery {
+                                return Err(ParseError::Invalid("subqueries are not allowed in CHECK constraints".to_string()));
+                            }
+                            match &self.current_token {
+                                Some(Token::RightParentheses) => {
+                                    self.advance();
+                                    constraints.push(Constraint::Check(expr));
+                                }
+                                Some(token) => return Err(ParseError::unexpected("')' after CHECK expression", token)),
+                                None => return Err(ParseError::eof("in CHECK constraint")),
+                            }
+                        }
+                        Some(token) => return Err(ParseError::unexpected("'(' after CHECK", token)),
+                        None => return Err(ParseError::eof("after CHECK")),
+                    }
+                }
+                Some(Token::Keyword(Keyword::References)) => {
+                    constraints.push(self.parse_references()?);
+                }
+                Some(Token::Identifier(_)) if starts_comment => {
+                    self.advance();
+                    if let Some(Token::String(text)) = self.current_token.take() {
+                        comment = Some(text);
+                    }
+                    self.advance();
+                }
+                _ => break,
+            }
+        }
+
+        Ok(TableColumn {
+            column_name,
+            column_type,
+            constraints,
+            comment,
+        })
+    }
+
+    /// Parses the required, parenthesized length after a string type, like the `(255)` of `VARCHAR(255)`
+    fn parse_type_length(&mut self, type_name: &str) -> Result<usize, ParseError> {
+        self.expect_token(Token::LeftParentheses)
+            .map_err(|e| e.expecting(format!("'(' after {}", type_name)))?;
+
+        let length = match &self.current_token {
+            Some(Token::Number(n)) => {
+                let length = *n as usize;
+                self.advance();
+                length
+            }
+            Some(token) => return Err(ParseError::unexpected(format!("number for {} length", type_name), token)),
+            None => return Err(ParseError::eof(format!("while parsing {} length", type_name))),
+        };
+
+        self.expect_token(Token::RightParentheses)
+            .map_err(|e| e.expecting(format!("')' after {} length", type_name)))?;
+        Ok(length)
+    }
+
+    fn parse_references(&mut self) -> Result<Constraint, ParseError> {
+        self.advance(); // Skip REFERENCES
+
+        // Parse referenced table and optional column
+        let table = match self.take_name_token() {
+            Some(Token::Identifier(name)) => {
+                self.advance();
+                name
+            }
+            Some(token) => return Err(ParseError::unexpected("table name after REFERENCES", &token)),
+            None => return Err(ParseError::eof("after REFERENCES")),
+        };
+
+        let mut column = None;
+        if let Some(Token::LeftParentheses) = self.current_token {
+            self.advance();
+            match self.take_name_token() {
+                Some(Token::Identifier(name)) => {
+                    self.advance();
+                    column = Some(name);
+                }
+                Some(token) => return Err(ParseError::unexpected("referenced column name", &token)),
+                None => return Err(ParseError::eof("while parsing referenced column")),
+            }
+            self.expect_token(Token::RightParentheses)?;
+        }
+
+        let (on_delete, on_update, match_type) = self.parse_reference_clauses()?;
+        Ok(Constraint::References {
+            table,
+            column,
+            on_delete,
+            on_update,
+            match_type,
+        })
+    }
+
+    /// Parses the optional `MATCH` and `ON DELETE` / `ON UPDATE` clauses after a reference, in any order
+    fn parse_reference_clauses(&mut self) -> Result<ReferenceClauses, ParseError> {
+        let mut on_delete = None;
+        let mut on_update = None;
+        let mut match_type = None;
+        loop {
+            match self.current_token {
+                Some(Token::Keyword(Keyword::Match)) => {
+                    self.advance();
+                    let parsed = match self.current_token {
+                        Some(Token::Keyword(Keyword::Full)) => MatchType::Full,
+                        Some(Token::Keyword(Keyword::Partial)) => MatchType::Partial,
+                        Some(Token::Keyword(Keyword::Simple)) => MatchType::Simple,
+                        Some(ref token) => return Err(ParseError::unexpected("FULL, PARTIAL or SIMPLE after MATCH", token)),
+                        None => return Err(ParseError::eof("after MATCH")),
+                    };
+                    self.advance();
+                    if match_type.replace(parsed).is_some() {
+                        return Err(ParseError::Invalid("MATCH specified more than once".to_string()));
+                    }
+                }
+                Some(Token::Keyword(Keyword::On)) => {
+                    self.advance();
+                    match self.current_token {
+                        Some(Token::Keyword(Keyword::Delete)) => {
+                            self.advance();
+                            let action = self.parse_referential_action()?;
+                            if on_delete.replace(action).is_some() {
+                                return Err(ParseError::Invalid("ON DELETE specified more than once".to_string()));
+                            }
+                        }
+                        Some(Token::Keyword(Keyword::Update)) => {
+                            self.advance();
+                            let action = self.parse_referential_action()?;
+                            if on_update.replace(action).is_some() {
+                                return Err(ParseError::Invalid("ON UPDATE specified more than once".to_string()));
+                            }
+                        }
+                        Some(ref token) => return Err(ParseError::unexpected("DELETE or UPDATE after ON", token)),
+                        None => return Err(ParseError::eof("after ON")),
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        Ok((on_delete, on_update, match_type))
+    }
+
+    fn parse_referential_action(&mut self) -> Result<ReferentialAction, ParseError> {
+        match self.current_token {
+            Some(Token::Keyword(Keyword::Cascade)) => {
+                self.advance();
+                Ok(ReferentialAction::Cascade)
+            }
+            Some(Token::Keyword(Keyword::Restrict)) => {
+                self.advance();
+                Ok(ReferentialAction::Restrict)
+            }
+            Some(Token::Keyword(Keyword::Set)) => {
+                self.advance();
+                self.expect_keyword(Keyword::Null)?;
+                Ok(ReferentialAction::SetNull)
+            }
+            Some(Token::Keyword(Keyword::No)) => {
+                self.advance();
+                self.expect_keyword(Keyword::Action)?;
+                Ok(ReferentialAction::NoAction)
+            }
+            Some(ref token) => Err(ParseError::unexpected("CASCADE, RESTRICT, SET NULL or NO ACTION", token)),
+            None => Err(ParseError::eof("while parsing referential action")),
+        }
+    }
+
+    pub fn parse_expression(&mut self) -> Result<Expression, ParseError> {
+        self.parse_expression_with_precedence(Precedence::None)
+    }
+
+    fn parse_expression_with_precedence(&mut self, precedence: Precedence) -> Result<Expression, ParseError> {
+        let mut left = self.parse_prefix()?;
+
+        while self.current_token.is_some() {
+            let current_precedence = self.current_precedence();
+            if precedence >= current_precedence {
+                break;
+            }
+            left = self.parse_infix(left)?;
+        }
+
+        Ok(left)
+    }
+
+    fn parse_prefix(&mut self) -> Result<Expression, ParseError> {
+        // A type keyword followed by a string is a typed literal like `DATE '2024-01-01'`, otherwise it is a name
+        let literal_type = match self.current_token {
+            Some(Token::Keyword(Keyword::Date)) => Some(DBType::Date),
+            Some(Token::Keyword(Keyword::Timestamp)) => Some(DBType::Timestamp),
+            Some(Token::Keyword(Keyword::Time)) => Some(DBType::Time),
+            _ => None,
+        };
+        if let Some(ty) = literal_type {
+            if let Some(Token::String(_)) = self.peek_token() {
+                self.advance();
+                if let Some(Token::String(value)) = self.current_token.take() {
+                    self.advance();
+                    return Ok(Expression::TypedLiteral { ty, value });
+                }
+            }
+        }
+
+        match self.take_name_token() {
+            Some(Token::Number(n)) => {
+                self.advance();
+                Ok(Expression::Number(n))
+            }
+            Some(Token::Float(n)) => {
+                self.advance();
+                Ok(Expression::Float(n))
+            }
+            Some(Token::String(s)) => {
+                self.advance();
+                Ok(Expression::String(s))
+            }
+            Some(Token::Identifier(i)) => {
+                self.advance();
+                // `table.column` or `table.*` refers to a column of a specific table
+                if let Some(Token::Dot) = self.current_token {
+                    self.advance();
+                    return match self.take_name_token() {
+                        Some(Token::Identifier(name)) => {
+                            self.advance();
+                            Ok(Expression::QualifiedIdentifier { qualifier: i, name })
+                        }
+                        Some(Token::Multiply) => {
+                            self.advance();
+                            Ok(Expression::QualifiedIdentifier { qualifier: i, name: "*".to_string() })
+                        }
+                        Some(token) => Err(ParseError::unexpected(format!("column name or * after '{}.'", i), &token)),
+                        None => Err(ParseError::eof(format!("after '{}.'", i))),
+                    };
+                }
+                // An identifier directly followed by '(' is a function call
+                if let Some(Token::LeftParentheses) = self.current_token {
+                    let call = self.parse_function_call(i)?;
+                    if let Some(Token::Keyword(Keyword::Over)) = self.current_token {
+                        return self.parse_over(call);
+                    }
+                    return Ok(call);
+                }
+                Ok(Expression::Identifier(i))
+            }
+            // REPLACE, LEFT and RIGHT are keywords in a projection or join, but they are
+            // also the names of string functions, which is what they mean in an expression
+            Some(Token::Keyword(keyword @ (Keyword::Replace | Keyword::Left | Keyword::Right))) => {
+                self.advance();
+                let name = keyword.to_string().to_uppercase();
+                match self.current_token {
+                    Some(Token::LeftParentheses) => self.parse_function_call(name),
+                    Some(ref token) => Err(ParseError::unexpected(format!("'(' after {}", name), token)),
+                    None => Err(ParseError::eof(format!("after {}", name))),
+                }
+            }
+            Some(Token::Keyword(Keyword::Case)) => {
+                self.advance();
+                self.parse_case()
+            }
+            Some(Token::Keyword(Keyword::True)) => {
+                self.advance();
+                Ok(Expression::Bool(true))
+            }
+            Some(Token::Keyword(Keyword::False)) => {
+                self.advance();
+                Ok(Expression::Bool(false))
+            }
+            Some(Token::Keyword(Keyword::Null)) => {
+                self.advance();
+                Ok(Expression::Null)
+            }
+            Some(Token::LeftParentheses) => {
+                self.advance();
+                // `()` is only meaningful as the argument list of a call like `NOW()`
+                if let Some(Token::RightParentheses) = self.current_token {
+                    return Err(ParseError::Invalid("empty parentheses are not a valid expression".to_string()));
+                }
+                // A parenthesized SELECT is a scalar subquery, like `x = (SELECT MAX(y) FROM t)`
+                if let Some(Token::Keyword(Keyword::Select)) = self.current_token {
+                    return Ok(Expression::Subquery(self.parse_subquery("subquery")?));
+                }
+                let expr = self.parse_expression()?;
+                match self.current_token {
+                    Some(Token::RightParentheses) => {
+                        self.advance();
+                        Ok(expr)
+                    }
+                    Some(ref token) => Err(ParseError::unexpected("closing parenthesis", token)),
+                    None => Err(ParseError::eof("inside parentheses")),
+                }
+            }
+            Some(Token::Minus) => {
+                self.advance();
+                let expr = self.parse_expression_with_precedence(Precedence::Unary)?;
+                Ok(Expression::UnaryOperation {
+                    operand: Box::new(expr),
+                    operator: UnaryOperator::Minus,
+                })
+            }
+            Some(Token::Plus) => {
+                self.advance();
+                let expr = self.parse_expression_with_precedence(Precedence::Unary)?;
+                Ok(Expression::UnaryOperation {
+                    operand: Box::new(expr),
+                    operator: UnaryOperator::Plus,
+                })
+            }
+            Some(Token::Keyword(Keyword::Not)) => {
+                self.advance();
+                if let Some(Token::Keyword(Keyword::Exists)) = self.current_token {
+                    self.advance();
+                    return self.parse_exists(true);
+                }
+                let expr = self.parse_expression_with_precedence(Precedence::Unary)?;
+                Ok(Expression::UnaryOperation {
+                    operand: Box::new(expr),
+                    operator: UnaryOperator::Not,
+                })
+            }
+            // `%` is decided by position: between two operands it is modulo (handled in parse_infix),
+            // while at the start of an expression it could only be a `%name` parameter placeholder as
+            // some drivers write them. No placeholder style is supported, so a leading `%` is an error.
+            Some(Token::Modulo) => Err(ParseError::Invalid(
+                "% needs a left operand, since %-style parameter placeholders are not supported".to_string(),
+            )),
+            // DISTINCT only has a meaning for a whole projection or for the arguments of an aggregate
+            Some(Token::Keyword(Keyword::Distinct)) => Err(ParseError::Invalid(
+                "DISTINCT is only allowed after SELECT or inside an aggregate function".to_string(),
+            )),
+            Some(Token::Keyword(Keyword::Exists)) => {
+                self.advance();
+                self.parse_exists(false)
+            }
+            Some(token) => Err(ParseError::unexpected("an expression", &token)),
+            None => Err(ParseError::UnexpectedEof { context: None }),
+        }
+    }
+
+    /// Parses the subquery of `[NOT] EXISTS`, starting at the '('
+    /// The operand has to be a subquery, so `EXISTS (1)` is rejected before its contents are parsed.
+    fn parse_exists(&mut self, negated: bool) -> Result<Expression, ParseError> {
+        self.expect_token(Token::LeftParentheses).map_err(|error| error.expecting("'(' after EXISTS"))?;
+        if !matches!(self.current_token, Some(Token::Keyword(Keyword::Select))) {
+            return Err(ParseError::Invalid("EXISTS requires a subquery".to_string()));
+        }
+        let subquery = self.parse_subquery("EXISTS subquery")?;
+        Ok(Expression::Exists { subquery, negated })
+    }
+
+    /// Parses a parenthesized subquery from its SELECT up to and including the closing parenthesis
+    /// The subquery ends at the parenthesis, without a semicolon of its own. `context` names it in errors.
+    fn parse_subquery(&mut self, context: &str) -> Result<Box<Statement>, ParseError> {
+        let subquery = self.parse_select()?;
+        self.expect_token(Token::RightParentheses).map_err(|error| error.expecting(format!("')' after {}", context)))?;
+        Ok(Box::new(subquery))
+    }
+
+    fn parse_infix(&mut self, left: Expression) -> Result<Expression, ParseError> {
+        match &self.current_token {
+            Some(Token::Keyword(Keyword::Like)) => {
+                self.advance();
+                self.parse_like(left, false)
+            }
+            Some(Token::Keyword(Keyword::In)) => {
+                self.advance();
+                self.parse_in_list(left, false)
+            }
+            Some(Token::Keyword(Keyword::Between)) => {
+                self.advance();
+                self.parse_between(left, false)
+            }
+            Some(Token::Keyword(Keyword::Is)) => self.parse_is(left),
+            Some(Token::Keyword(Keyword::Not)) => {
+                self.advance();
+                match self.current_token {
+                    Some(Token::Keyword(Keyword::Like)) => {
+                        self.advance();
+                        self.parse_like(left, true)
+                    }
+                    Some(Token::Keyword(Keyword::In)) => {
+                        self.advance();
+                        self.parse_in_list(left, true)
+                    }
+                    Some(Token::Keyword(Keyword::Between)) => {
+                        self.advance();
+                        self.parse_between(left, true)
+                    }
+                    Some(ref token) => Err(ParseError::unexpected("LIKE, IN or BETWEEN after NOT", token)),
+                    None => Err(ParseError::eof("after NOT")),
+                }
+            }
+            Some(token) => {
+                if *token == Token::NullSafeEqual && self.dialect != Dialect::MySql {
+                    return Err(ParseError::Invalid(format!("<=> is not supported in the {} dialect", self.dialect.name())));
+                }
+                let precedence = self.get_precedence(token);
+                let operator = match token {
+                    Token::Plus => BinaryOperator::Plus,
+                    Token::Minus => BinaryOperator::Minus,
+                    Token::Multiply => BinaryOperator::Multiply,
+                    Token::Divide => BinaryOperator::Divide,
+                    Token::Modulo => BinaryOperator::Modulo,
+                    Token::Concat if self.dialect == Dialect::MySql => BinaryOperator::Or,
+                    Token::Concat => BinaryOperator::Concat,
+                    Token::GreaterThan => BinaryOperator::GreaterThan,
+                    Token::GreaterThanOrEqual => BinaryOperator::GreaterThanOrEqual,
+                    Token::LessThan => BinaryOperator::LessThan,
+                    Token::LessThanOrEqual => BinaryOperator::LessThanOrEqual,
+                    Token::Equal => BinaryOperator::Equal,
+                    Token::NotEqual => BinaryOperator::NotEqual,
+                    Token::NullSafeEqual => BinaryOperator::NullSafeEqual,
+                    Token::Keyword(Keyword::And) => BinaryOperator::And,
+                    Token::Keyword(Keyword::Or) => BinaryOperator::Or,
+                    _ => return Err(ParseError::Invalid(format!("Invalid infix operator: {:?}", token))),
+                };
+                self.advance();
+                let right = self.parse_expression_with_precedence(precedence)?;
+
+                Ok(Expression::BinaryOperation {
+                    left_operand: Box::new(left),
+                    operator,
+                    right_operand: Box::new(right),
+                })
+            }
+            None => Err(ParseError::UnexpectedEof { context: None }),
+        }
+    }
+
+    /// Parses the parenthesized argument list of a function call, starting at the '('
+    /// Any number of arguments is accepted, including none (`NOW()`), since arity is the database's concern.
+    /// A lone `*` argument, as in `COUNT(*)`, is kept as the identifier `*` like a `SELECT *` projection.
+    /// A leading `DISTINCT`, as in `COUNT(DISTINCT user_id)`, sets `distinct` and requires at least one argument.
+    fn parse_function_call(&mut self, name: String) -> Result<Expression, ParseError> {
+        self.advance(); // Skip '('
+
+        let mut distinct = false;
+        if let Some(Token::Keyword(Keyword::Distinct)) = self.current_token {
+            self.advance();
+            distinct = true;
+        }
+
+        let mut args = Vec::new();
+        if distinct {
+            if let Some(Token::RightParentheses) = self.current_token {
+                return Err(ParseError::unexpected(format!("an argument after DISTINCT in call to {}", name), &Token::RightParentheses));
+            }
+        } else {
+            if let Some(Token::RightParentheses) = self.current_token {
+                self.advance();
+                return Ok(Expression::FunctionCall { name, distinct, args });
+            }
+
+            if self.current_token == Some(Token::Multiply) && self.peek_token() == Some(&Token::RightParentheses) {
+                self.advance();
+                self.advance();
+                args.push(Expression::Identifier("*".to_string()));
+                return Ok(Expression::FunctionCall { name, distinct, args });
+            }
+        }
+
+        loop {
+            args.push(self.parse_expression()?);
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                Some(Token::RightParentheses) => {
+                    self.advance();
+                    break;
+                }
+                Some(ref token) => return Err(ParseError::unexpected(format!("comma or closing parenthesis in call to {}", name), token)),
+                None => return Err(ParseError::eof(format!("in call to {}", name))),
+            }
+        }
+
+        Ok(Expression::FunctionCall { name, distinct, args })
+    }
+
+    /// Parses the `OVER` clause that turns `function` into a window function
+    /// The window is either the name of a window from the `WINDOW` clause or a parenthesized specification.
+    fn parse_over(&mut self, function: Expression) -> Result<Expression, ParseError> {
+        self.advance(); // Skip OVER
+
+        let window = match self.take_identifier() {
+            Some(name) => Window::Named(name),
+            None => match &self.current_token {
+                Some(Token::LeftParentheses) => Window::Spec(Box::new(self.parse_window_spec()?)),
+                Some(token) => return Err(ParseError::unexpected("window name or '(' after OVER", token)),
+                None => return Err(ParseError::eof("after OVER")),
+            },
+        };
+
+        Ok(Expression::WindowFunction {
+            function: Box::new(function),
+            window,
+        })
+    }
+
+    /// Parses a parenthesized window specification: `([PARTITION BY ...] [ORDER BY ...] [frame])`
+    fn parse_window_spec(&mut self) -> Result<WindowSpec, ParseError> {
+        self.expect_token(Token::LeftParentheses)?;
+
+        let mut partition_by = Vec::new();
+        if let Some(Token::Keyword(Keyword::Partition)) = self.current_token {
+            self.advance();
+            self.expect_keyword(Keyword::By)?;
+            loop {
+                partition_by.push(self.parse_expression()?);
+                match self.current_token {
+                    Some(Token::Comma) => {
+                        self.advance();
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        let mut order_by = Vec::new();
+        if let Some(Token::Keyword(Keyword::Order)) = self.current_token {
+            self.advance();
+            self.expect_keyword(Keyword::By)?;
+            loop {
+                order_by.push(self.parse_order_by_item()?);
+                match self.current_token {
+                    Some(Token::Comma) => {
+                        self.advance();
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        let frame = match self.current_token {
+            Some(Token::Keyword(Keyword::Rows)) => {
+                self.advance();
+                Some(self.parse_window_frame(FrameUnits::Rows)?)
+            }
+            Some(Token::Keyword(Keyword::Range)) => {
+                self.advance();
+                Some(self.parse_window_frame(FrameUnits::Range)?)
+            }
+            _ => None,
+        };
+
+        match self.current_token {
+            Some(Token::RightParentheses) => self.advance(),
+            Some(ref token) => return Err(ParseError::unexpected("closing parenthesis after window specification", token)),
+            None => return Err(ParseError::eof("in window specification")),
+        };
+
+        Ok(WindowSpec {
+            partition_by,
+            order_by,
+            frame,
+        })
+    }
+
+    /// Parses the bounds and optional EXCLUDE clause of a window frame whose ROWS/RANGE keyword was already consumed
+    fn parse_window_frame(&mut self, units: FrameUnits) -> Result<WindowFrame, ParseError> {
+        let (start, end) = if let Some(Token::Keyword(Keyword::Between)) = self.current_token {
+            self.advance();
+            let start = self.parse_frame_bound()?;
+            self.expect_keyword(Keyword::And)?;
+            (start, Some(self.parse_frame_bound()?))
+        } else {
+            (self.parse_frame_bound()?, None)
+        };
+
+        if start == FrameBound::UnboundedFollowing {
+            return Err(ParseError::Invalid("A window frame cannot start at UNBOUNDED FOLLOWING".to_string()));
+        }
+        if end == Some(FrameBound::UnboundedPreceding) {
+            return Err(ParseError::Invalid("A window frame cannot end at UNBOUNDED PRECEDING".to_string()));
+        }
+
+        let mut exclude = None;
+        if let Some(Token::Keyword(Keyword::Exclude)) = self.current_token {
+            self.advance();
+            exclude = Some(match self.current_token {
+                Some(Token::Keyword(Keyword::Current)) => {
+                    self.advance();
+                    self.expect_keyword(Keyword::Row)?;
+                    FrameExclusion::CurrentRow
+                }
+                Some(Token::Keyword(Keyword::Group)) => {
+                    self.advance();
+                    FrameExclusion::Group
+                }
+                Some(Token::Keyword(Keyword::Ties)) => {
+                    self.advance();
+                    FrameExclusion::Ties
+                }
+                Some(Token::Keyword(Keyword::No)) => {
+                    self.advance();
+                    self.expect_keyword(Keyword::Others)?;
+                    FrameExclusion::NoOthers
+                }
+                Some(ref token) => return Err(ParseError::unexpected("CURRENT ROW, GROUP, TIES or NO OTHERS after EXCLUDE", token)),
+                None => return Err(ParseError::eof("after EXCLUDE")),
+            });
+        }
+
+        Ok(WindowFrame {
+            units,
+            start,
+            end,
+            exclude,
+        })
+    }
+
+    fn parse_frame_bound(&mut self) -> Result<FrameBound, ParseError> {
+        match self.current_token {
+            Some(Token::Keyword(Keyword::Unbounded)) => {
+                self.advance();
+                match self.current_token {
+                    Some(Token::Keyword(Keyword::Preceding)) => {
+                        self.advance();
+                        Ok(FrameBound::UnboundedPreceding)
+                    }
+                    Some(Token::Keyword(Keyword::Following)) => {
+                        self.advance();
+                        Ok(FrameBound::UnboundedFollowing)
+                    }
+                    Some(ref token) => Err(ParseError::unexpected("PRECEDING or FOLLOWING after UNBOUNDED", token)),
+                    None => Err(ParseError::eof("after UNBOUNDED")),
+                }
+            }
+            Some(Token::Keyword(Keyword::Current)) => {
+                self.advance();
+                self.expect_keyword(Keyword::Row)?;
+                Ok(FrameBound::CurrentRow)
+            }
+            _ => {
+                let offset = self.parse_expression()?;
+                match self.current_token {
+                    Some(Token::Keyword(Keyword::Preceding)) => {
+                        self.advance();
+                        Ok(FrameBound::Preceding(offset))
+                    }
+                    Some(Token::Keyword(Keyword::Following)) => {
+                        self.advance();
+                        Ok(FrameBound::Following(offset))
+                    }
+                    Some(ref token) => Err(ParseError::unexpected("PRECEDING or FOLLOWING after frame offset", token)),
+                    None => Err(ParseError::eof("after frame offset")),
+                }
+            }
+        }
+    }
+
+    /// Parses the pattern of a `[NOT] LIKE` operator whose keywords were already consumed
+    fn parse_like(&mut self, expr: Expression, negated: bool) -> Result<Expression, ParseError> {
+        let pattern = self.parse_expression_with_precedence(Precedence::Compare)?;
+        Ok(Expression::Like {
+            expr: Box::new(expr),
+            pattern: Box::new(pattern),
+            negated,
+        })
+    }
+
+    /// Parses a CASE expression after the CASE keyword, in its simple (`CASE x WHEN 1 THEN ...`)
+    /// or searched (`CASE WHEN x > 0 THEN ...`) form
+    fn parse_case(&mut self) -> Result<Expression, ParseError> {
+        let operand = match self.current_token {
+            Some(Token::Keyword(Keyword::When | Keyword::End)) => None,
+            _ => Some(Box::new(self.parse_expression()?)),
+        };
+
+        let mut when_clauses = Vec::new();
+        while let Some(Token::Keyword(Keyword::When)) = self.current_token {
+            self.advance();
+            let condition = self.parse_expression()?;
+            self.expect_keyword(Keyword::Then)
+                .map_err(|e| e.expecting("THEN after the WHEN condition"))?;
+            let result = self.parse_expression()?;
+            when_clauses.push((condition, result));
+        }
+        if when_clauses.is_empty() {
+            return Err(ParseError::Invalid("CASE requires at least one WHEN clause".to_string()));
+        }
+
+        let mut else_clause = None;
+        if let Some(Token::Keyword(Keyword::Else)) = self.current_token {
+            self.advance();
+            else_clause = Some(Box::new(self.parse_expression()?));
+        }
+
+        match self.current_token {
+            Some(Token::Keyword(Keyword::End)) => {
+                self.advance();
+                Ok(Expression::Case {
+                    operand,
+                    when_clauses,
+                    else_clause,
+                })
+            }
+            Some(ref token) => Err(ParseError::unexpected("WHEN, ELSE or END in CASE", token)),
+            None => Err(ParseError::eof("inside CASE")),
+        }
+    }
+
+    /// Parses `IS [NOT] NULL` or `IS [NOT] DISTINCT FROM expr`, starting at the IS keyword
+    fn parse_is(&mut self, expr: Expression) -> Result<Expression, ParseError> {
+        for (keywords, negated) in [
+            (&[Keyword::Is, Keyword::Null][..], false),
+            (&[Keyword::Is, Keyword::Not, Keyword::Null][..], true),
+        ] {
+            if self.try_consume_keywords(keywords) {
+                return Ok(Expression::IsNull {
+                    expr: Box::new(expr),
+                    negated,
+                });
+            }
+        }
+
+        for (keywords, negated) in [
+            (&[Keyword::Is, Keyword::Distinct, Keyword::From][..], false),
+            (&[Keyword::Is, Keyword::Not, Keyword::Distinct, Keyword::From][..], true),
+        ] {
+            if self.try_consume_keywords(keywords) {
+                let right = self.parse_expression_with_precedence(Precedence::Compare)?;
+                return Ok(Expression::IsDistinctFrom {
+                    left: Box::new(expr),
+                    right: Box::new(right),
+                    negated,
+                });
+            }
+        }
+
+        self.advance(); // Skip IS
+        let context = if self.try_consume_keywords(&[Keyword::Not]) { "IS NOT" } else { "IS" };
+        match self.current_token {
+            Some(ref token) => Err(ParseError::unexpected(format!("NULL or DISTINCT FROM after {}", context), token)),
+            None => Err(ParseError::eof(format!("after {}", context))),
+        }
+    }
+
+    /// Parses the bounds of a `[NOT] BETWEEN low AND high` operator whose keywords were already consumed
+    /// Both bounds are parsed above `AND`, so the `AND` separating them is never taken for a logical AND,
+    /// and `a BETWEEN 1 AND 2 AND b` leaves `AND b` to the surrounding expression.
+    fn parse_between(&mut self, expr: Expression, negated: bool) -> Result<Expression, ParseError> {
+        let low = self.parse_expression_with_precedence(Precedence::Compare)?;
+        self.expect_keyword(Keyword::And)
+            .map_err(|e| e.expecting("AND between the bounds of BETWEEN"))?;
+        let high = self.parse_expression_with_precedence(Precedence::Compare)?;
+        Ok(Expression::Between {
+            expr: Box::new(expr),
+            low: Box::new(low),
+            high: Box::new(high),
+            negated,
+        })
+    }
+
+    /// Parses the parenthesized right side of `[NOT] IN`, either a list of values or a subquery
+    fn parse_in_list(&mut self, expr: Expression, negated: bool) -> Result<Expression, ParseError> {
+        self.expect_token(Token::LeftParentheses)
+            .map_err(|e| e.expecting("'(' after IN"))?;
+        if let Some(Token::RightParentheses) = self.current_token {
+            return Err(ParseError::Invalid("IN list must contain at least one value".to_string()));
+        }
+        if let Some(Token::Keyword(Keyword::Select)) = self.current_token {
+            return Ok(Expression::InSubquery {
+                expr: Box::new(expr),
+                subquery: self.parse_subquery("IN subquery")?,
+                negated,
+            });
+        }
+
+        let mut list = Vec::new();
+        loop {
+            list.push(self.parse_expression()?);
+
+            match self.current_token {
+                Some(Token::Comma) => {
+                    self.advance();
+                    continue;
+                }
+                Some(Token::RightParentheses) => {
+                    self.advance();
+                    break;
+                }
+                Some(ref token) => return Err(ParseError::unexpected("comma or closing parenthesis in IN list", token)),
+                None => return Err(ParseError::eof("in IN list")),
+            }
+        }
+
+        Ok(Expression::InList {
+            expr: Box::new(expr),
+            list,
+            negated,
+        })
+    }
+
+    pub fn parse_order_by_item(&mut self) -> Result<OrderByItem, ParseError> {
+        let expr = self.parse_expression()?;
+
+        // Check for ASC/DESC
+        let direction = match self.current_token {
+            Some(Token::Keyword(Keyword::Asc)) => {
+                self.advance();
+                Some(SortDirection::Asc)
+            }
+            Some(Token::Keyword(Keyword::Desc)) => {
+                self.advance();
+                Some(SortDirection::Desc)
+            }
+            _ => None, // No direction specified, which defaults to ASC
+        };
+
+        Ok(OrderByItem { expr, direction })
+    }
+}
@@ -0,0 +1,108 @@
+/// Rewrite module for parsed expressions
+/// This module contains transformations that turn an expression into an equivalent, simpler one.
+/// Rewrites take the expression by value and return the rewritten tree.
+use crate::statement::{BinaryOperator, Expression, UnaryOperator};
+
+/// Pushes `NOT` into comparisons by negating the operator, e.g. `NOT (a = b)` becomes `a != b`
+/// and `NOT (a < b)` becomes `a >= b`. This holds under SQL's three-valued logic too, since both
+/// forms are unknown when either operand is null. `NOT` over anything other than a comparison is
+/// left in place. The rewrite is applied throughout the tree, innermost expressions first.
+pub fn simplify_not(expr: Expression) -> Expression {
+    match expr {
+        Expression::UnaryOperation { operand, operator: UnaryOperator::Not } => {
+            match simplify_not(*operand) {
+                Expression::BinaryOperation { left_operand, operator, right_operand } => {
+                    match negated_comparison(&operator) {
+                        Some(negated) => Expression::BinaryOperation {
+                            left_operand,
+                            operator: negated,
+                            right_operand,
+                        },
+                        None => Expression::UnaryOperation {
+                            operand: Box::new(Expression::BinaryOperation { left_operand, operator, right_operand }),
+                            operator: UnaryOperator::Not,
+                        },
+                    }
+                }
+                operand => Expression::UnaryOperation {
+                    operand: Box::new(operand),
+                    operator: UnaryOperator::Not,
+                },
+            }
+        }
+        Expression::UnaryOperation { operand, operator } => Expression::UnaryOperation {
+            operand: Box::new(simplify_not(*operand)),
+            operator,
+        },
+        Expression::BinaryOperation { left_operand, operator, right_operand } => Expression::BinaryOperation {
+            left_operand: Box::new(simplify_not(*left_operand)),
+            operator,
+            right_operand: Box::new(simplify_not(*right_operand)),
+        },
+        Expression::Like { expr, pattern, negated } => Expression::Like {
+            expr: Box::new(simplify_not(*expr)),
+            pattern: Box::new(simplify_not(*pattern)),
+            negated,
+        },
+        Expression::IsDistinctFrom { left, right, negated } => Expression::IsDistinctFrom {
+            left: Box::new(simplify_not(*left)),
+            right: Box::new(simplify_not(*right)),
+            negated,
+        },
+        Expression::IsNull { expr, negated } => Expression::IsNull {
+            expr: Box::new(simplify_not(*expr)),
+            negated,
+        },
+        Expression::Between { expr, low, high, negated } => Expression::Between {
+            expr: Box::new(simplify_not(*expr)),
+            low: Box::new(simplify_not(*low)),
+            high: Box::new(simplify_not(*high)),
+            negated,
+        },
+        Expression::InList { expr, list, negated } => Expression::InList {
+            expr: Box::new(simplify_not(*expr)),
+            list: list.into_iter().map(simplify_not).collect(),
+            negated,
+        },
+        Expression::InSubquery { expr, subquery, negated } => Expression::InSubquery {
+            expr: Box::new(simplify_not(*expr)),
+            subquery,
+            negated,
+        },
+        Expression::FunctionCall { name, distinct, args } => Expression::FunctionCall {
+            name,
+            distinct,
+            args: args.into_iter().map(simplify_not).collect(),
+        },
+        Expression::Aliased { expr, alias } => Expression::Aliased {
+            expr: Box::new(simplify_not(*expr)),
+            alias,
+        },
+        Expression::WindowFunction { function, window } => Expression::WindowFunction {
+            function: Box::new(simplify_not(*function)),
+            window,
+        },
+        Expression::Case { operand, when_clauses, else_clause } => Expression::Case {
+            operand: operand.map(|operand| Box::new(simplify_not(*operand))),
+            when_clauses: when_clauses
+                .into_iter()
+                .map(|(condition, result)| (simplify_not(condition), simplify_not(result)))
+                .collect(),
+            else_clause: else_clause.map(|else_clause| Box::new(simplify_not(*else_clause))),
+        },
+        leaf => leaf,
+    }
+}
+
+/// The comparison that holds exactly when `operator` does not, or `None` for other operators
+fn negated_comparison(operator: &BinaryOperator) -> Option<BinaryOperator> {
+    match operator {
+        BinaryOperator::Equal => Some(BinaryOperator::NotEqual),
+        BinaryOperator::NotEqual => Some(BinaryOperator::Equal),
+        BinaryOperator::LessThan => Some(BinaryOperator::GreaterThanOrEqual),
+        BinaryOperator::LessThanOrEqual => Some(BinaryOperator::GreaterThan),
+        BinaryOperator::GreaterThan => Some(BinaryOperator::LessThanOrEqual),
+        BinaryOperator::GreaterThanOrEqual => Some(BinaryOperator::LessThan),
+        _ => None,
+    }
+}
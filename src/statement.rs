@@ -1,486 +1,1451 @@
-use std::fmt::{Debug, Display, Formatter};
-
-/// The main entity of the whole parser. `Statement` is implemented as an enumeration because adding functionality is as easy as adding an enumeration constant and implementing functionality for that enumeration constant (implementation in the database command interpreter, which is not a part of this project). Parsing any correct `SELECT` or `CREATE`  (or `UPDATE`, `INSERT INTO`, ... hypothetically) statement should be turned into an instance of this enumeration. Ultimately, your main parser function (something like `build_statement(query: &str) -> Statement`) should return this enumeration.
-///
-/// The `SELECT` statement has four components:
-/// 1. `columns` – A vector of columns from the selected table that the database should return.
-/// 2. `from` – A simple string, containing a table that is being queried (we aren't doing joins because they complicate stuff too much for this project).
-/// 3. `where` – A single expression that is the actual filter for the database query. It is wrapped in an `Option` because not every `SELECT` query contains a filter. The actual name is `r#where` because in Rust, `where` is a reserved keyword, and the prefix `r#` means: interpret this token as a raw string, do not check for keyword matches.
-/// 4. `orderby` – A vector of expressions that define how should the data be ordered. A vector is needed because the data can be ordered by the first column, and then all data that has the same first column can be ordered by the second column, ... Also, the data can be ordered not simply by columns, but by complex expressions as well.
-///
-/// The `CREATE TABLE` statement has two components:
-/// 1. `table_name` – A simple string, the name of the table.
-/// 2. `column_list` – A vector of table column types, where each table column contains the definition of one column.
-///
-/// Examples:
-///
-/// ---
-/// ```sql
-/// SELECT name, surname FROM users;
-/// ```
-/// is a `SELECT` statement that,  when parsed, looks like this:
-/// ```rust
-/// Statement::Select {
-///     columns: vec![
-/// 		Expression::Identifier("name".to_string()),
-/// 		Expression:Identifier("surname".to_string())
-/// 	],
-///     from: "users".to_string(),
-///     r#where: None,
-///     orderby: vec![]
-/// }
-/// ```
-/// ---
-/// ```sql
-/// SELECT age * 5, 'this is a string' FROM users;
-/// ```
-/// is a `SELECT` statement that,  when parsed, looks like this:
-/// ```rust
-/// Statement::Select {
-///     columns: vec![
-///         Expression::BinaryOperation {
-///             left_operand: Box::new(Expression::Identifier("age".to_string())),
-///             operator: BinaryOperator::Multiply,
-///             right_operand: Box::new(Expression::Number(5)),
-///         },
-///         Expression::String("this is a string".to_string()),
-///     ],
-///     from: "users".to_string(),
-///     r#where: None,
-///     orderby: vec![]
-/// }
-/// ```
-/// ---
-/// ```sql
-/// SELECT name, surname FROM users WHERE name = \"Voldemort\" AND surname = 'Riddle';
-/// ```
-/// is a  `SELECT` statement that, when parsed, looks like this:
-/// ```rust
-/// Statement::Select {
-///     columns: [
-///         Expression::Identifier("name".to_string()),
-///         Expression::Identifier("surname".to_string()),
-///     ],
-///     from: "users".to_string(),
-///     r#where: Some(
-///         Expression::BinaryOperation {
-///             left_operand: Box::new(Expression::BinaryOperation {
-///                 left_operand: Box::new(Expression::Identifier("name".to_string())),
-///                 operand: BinaryOperator::Equals,
-///                 right_operand: Box::new(Expression::String("Voldemort".to_string())),
-///             }),
-///             operand: BinaryOperator::And,
-///             right_operand: Box::new(Expression::BinaryOperation {
-///                 left_operand: Box::new(Expression::Identifier("surname".to_string())),
-///                 operand: BinaryOperator::Equals,
-///                 right_operand: Box::new(Expression::String("Riddle".to_string())),
-///             }),
-///         },
-///     ),
-///     orderby: vec![]
-/// }
-/// ```
-///  ---
-/// ```sql
-/// SELECT id, salary FROM users ORDER BY salary - 2 * 10 ASC, id DESC;
-/// ```
-/// is a  `SELECT` statement that, when parsed, looks like this:
-/// ```rust
-/// Statement::Select {
-///     columns: vec![
-///         Expression::Identifier("id".to_string()),
-///         Expression::Identifier("salary".to_string()),
-///     ],
-///     from: "users".to_string(),
-///     r#where: None,
-///     orderby: vec![
-///         Expression::UnaryOperation {
-///             operand: Box::new(Expression::BinaryOperation {
-///                 left_operand: Box::new(Expression::Identifier("salary".to_string())),
-///                 operator: BinaryOperator::Minus,
-///                 right_operand: Box::new(Expression::BinaryOperation {
-///                     left_operand: Box::new(Expression::Number(2)),
-///                     operator: BinaryOperator::Multiply,
-///                     right_operand: Box::new(Expression::Number(10)),
-///                 }),
-///             }),
-///             operator: UnaryOperator::Asc,
-///         },
-///         Expression::UnaryOperation {
-///             operand: Box::new(Expression::Identifier("id".to_string())),
-///             operator: UnaryOperator::Desc,
-///         },
-///     ],
-/// }
-/// ```
-///  ---
-/// ```sql
-/// SELECT id FROM registered_users WHERE password_encryption = TRUE ORDER BY id DESC;
-/// ```
-/// is a  `SELECT` statement that, when parsed, looks like this:
-/// ```rust
-/// Statement::Select {
-///     columns: vec![
-///         Expression::Identifier("id".to_string())
-///     ],
-///     from: "registered_users".to_string(),
-///     r#where: Some(
-///         Expression::BinaryOperation {
-///             left_operand: Box::new(Expression::Identifier("password_encryption".to_string())),
-///             operator: BinaryOperator::Equals,
-///             right_operand: Box::new(Expression::Bool(true))
-///         }
-///     ),
-///     orderby: vec![
-///         Expression::UnaryOperation {
-///             operand: Box::new(Expression::Identifier("id".to_string())),
-///             operator: UnaryOperator::Desc
-///         }
-///     ]
-/// }
-/// ```
-/// ---
-/// ```sql
-/// CREATE TABLE simple_table(
-/// 	int_col INT,
-/// 	string_col VARCHAR(255),
-/// 	bool_col BOOL
-/// );
-/// ```
-/// is a  `CREATE TABLE` statement that, when parsed, looks like this:
-/// ```rust
-/// Statement::CreateTable {
-///     table_name: "simple_table".to_string(),
-///     column_list: vec![
-///         TableColumn {
-///             column_name: "int_col".to_string(),
-///             column_type: DBType::Int,
-///             constraints: vec![],
-///         },
-///         TableColumn {
-///             column_name: "string_col".to_string(),
-///             column_type: DBType::Varchar(255),
-///             constraints: vec![],
-///         },
-///         TableColumn {
-///             column_name: "bool_col".to_string(),
-///             column_type: DBType::Bool,
-///             constraints: vec![],
-///         },
-///     ]
-/// }
-/// ```
-/// ---
-/// ```sql
-/// CREATE TABLE complex_table(
-/// 	id INT PRIMARY KEY,
-/// 	email VARCHAR(255) NOT NULL,
-/// 	is_junior BOOL,
-/// 	age INT CHECK(age >= 18) CHECK(age <= 65)
-/// );
-/// ```
-/// is a  `CREATE TABLE` statement that, when parsed, looks like this:
-/// ```rust
-/// Statement::CreateTable {
-///     table_name: Expression::Identifier("complex_table".to_string()),
-///     column_list: vec![
-///         TableColumn {
-///             column_name: Expression::Identifier("id".to_string()),
-///             column_type: DBType::Int,
-///             constraints: vec![
-///                 Constraint::PrimaryKey,
-///             ],
-///         },
-///         TableColumn {
-///             column_name: Expression::Identifier("email".to_string()),
-///             column_type: DBType::Varchar(255),
-///             constraints: vec![
-///                 Constraint::NotNull,
-///             ],
-///         },
-///         TableColumn {
-///             column_name: Expression::Identifier("is_junior".to_string()),
-///             column_type: DBType::Bool,
-///             constraints: vec![],
-///         },
-///         TableColumn {
-///             column_name: Expression::Identifier("age".to_string()),
-///             column_type: DBType::Int,
-///             constraints: vec![
-///                 Constraint::Check(Expression::BinaryOperation {
-///                     left_operand: Box::new(Expression::Identifier("age".to_string())),
-///                     operator: BinaryOperator::GreaterThanOrEqual,
-///                     right_operand: Box::new(Expression::Number(18)),
-///                 }),
-///                 Constraint::Check(Expression::BinaryOperation {
-///                     left_operand: Box::new(Expression::Identifier("age".to_string())),
-///                     operator: BinaryOperator::LessThanOrEqual,
-///                     right_operand: Box::new(Expression::Number(65)),
-///                 }),
-///             ],
-///         },
-///     ],
-/// }
-/// ```
-/// ---
-/// ```sql
-/// SELECT salary WHERE salary > 1000;
-/// ```
-/// is a string, that, the parser should throw an error to the user when it encounters it (no `FROM` clause).
-///
-/// ---
-/// ```sql
-/// CREATE TABLE work_hours(num_hours INT)
-/// ```
-/// is a string, that, the parser should throw an error to the user when it encounters it (no semicolon at the end).
-#[derive(Debug, PartialEq)]
-pub enum Statement {
-    Select {
-        columns: Vec<Expression>,
-        from: String,
-        r#where: Option<Expression>,
-        orderby: Vec<Expression>,
-    },
-    CreateTable {
-        table_name: String,
-        column_list: Vec<TableColumn>,
-    }
-}
-
-/// The main entity of the expression parser. The Expression enum is structured like this, where an expression can contain another expression. This naturally allows us to represent complex expressions as trees. `Box<T>` smart pointers are used on unary and binary types of expressions because the compiler needs to know the size of the enum at compile time which is impossible when an enum contains itself (infinite size).
-///
-/// An expression can be:
-/// * complex - a number of other expressions (tree-like structure, unary and binary operations)
-/// * a single number
-/// * a single identifier (like a variable 'x')
-/// * a single string (when doing parsing of WHERE statements that do operations with strings, strings must be in matching quotes – either `""` or `''`)
-/// * a boolean (only true or false)
-///
-/// Examples:
-///
-/// ---
-/// ```
-/// (13 + 7) - 4
-/// ```
-/// is an expression that contains two expressions:
-/// 1. `(13 + 7)` which is
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Box::new(Expression::Number(13)),
-///     operator: BinaryOperator::Plus,
-///     right_operand: Box::new(Expression::Number(7))
-/// }
-/// ```
-/// 2. `4` which is
-/// ```rust
-/// Expression::Number(4)
-/// ```
-/// Therefore, the whole expression after parsing should look like this:
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Expression::BinaryOperation {
-///         left_operand: Box::new(Expression::Number(13)),
-///         operator: BinaryOperator::Plus,
-///         right_operand: Box::new(Expression::Number(7))
-///     },
-///     operator: BinaryOperator::Minus,
-///     right_operand: Box::new(Expression::Number(4))
-/// }
-/// ```
-/// ---
-/// ```
-/// (5 - x) < (4 + y) OR name = "Donna"
-/// ```
-/// is an expression that contains five (three small and two combining) expressions:
-/// 1. `(5 - x)` which is
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Box::new(Expression::Number(5)),
-///     operator: BinaryOperator::Minus,
-///     right_operand: Box::new(Expression::Identifier("x".to_string())),
-/// }
-/// ```
-/// 2. `(4 - y)` which is
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Box::new(Expression::Number(4)),
-///     operator: BinaryOperator::Plus,
-///     right_operand: Box::new(Expression::Identifier("y".to_string()))
-/// }
-/// ```
-/// 3. `name = "Donna"` which is
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Box::new(Expression::Identifier("name".to_string())),
-///     operator: BinaryOperator::Equal,
-///     right_operand: Box::new(Expression::String("Donna".to_string()))
-/// }
-/// ```
-/// Therefore, the whole expression after parsing should look like this:
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Box::new(Expression::BinaryOperation {
-///         left_operand: Box::new(Expression::BinaryOperation {
-///             left_operand: Box::new(Expression::Number(5)),
-///             operator: BinaryOperator::Minus,
-///             right_operand: Box::new(Expression::Identifier("x".to_string()))
-///         }),
-///         operator: BinaryOperator::LessThan,
-///         right_operand: Box::new(Expression::BinaryOperation {
-///             left_operand: Box::new(Expression::Number(4)),
-///             operator: BinaryOperator::Plus,
-///             right_operand: Box::new(Expression::Identifier("y".to_string()))
-///         })
-///     }),
-///     operator: BinaryOperator::Or,
-///     right_operand: Box::new(Expression::BinaryOperation {
-///         left_operand: Box::new(Expression::Identifier("name".to_string())),
-///         operator: BinaryOperator::Equal,
-///         right_operand: Box::new(Expression::String("Donna".to_string()))
-///     })
-/// }
-/// ```
-/// ---
-/// ```
-/// NOT some_boolean = TRUE
-/// ```
-/// should look like this:
-/// ```rust
-/// Expression::BinaryOperation {
-///     left_operand: Box::new(Expression::UnaryOperation {
-///         left_operand: Box::new(Expression::Identifier("some_boolean".to_string())),
-///         operator: UnaryOperator::Not
-///     }),
-///     operator: BinaryOperator::Equal,
-///     right_operand: Box::new(Expression::Bool(true))
-/// }
-/// ```
-/// ---
-/// ```
-/// 5 * 3 - 4 + c / (13 -)
-/// ```
-/// is a string, that, the parser should throw an error to the user when it encounters it.
-
-#[derive(Debug, PartialEq)]
-pub enum Expression {
-    BinaryOperation {
-        left_operand: Box<Expression>,
-        operator: BinaryOperator,
-        right_operand: Box<Expression>,
-    },
-    UnaryOperation {
-        operand: Box<Expression>,
-        operator: UnaryOperator,
-    },
-    Number(u64),
-    Bool(bool),
-    Identifier(String),
-    String(String),
-}
-
-/// A structure containing a definition for one column, when creating a table.
-/// 1. `column_name` – A simple string, representing a name.
-/// 2. `column_type` – The type of the column. Types are defined in the `DBType` enum.
-/// 3.  `constraints` – A vector of constraints on the column. Types of constraints are defined in the `Constraint` enum.
-#[derive(Debug, PartialEq)]
-pub struct TableColumn {
-    pub column_name: String,
-    pub column_type: DBType,
-    pub constraints: Vec<Constraint>,
-}
-
-/// A column in the database can be any of these types. `Int` and `Bool` types have no additional info, while the `Varchar(n)` type has an additional argument – the length of the string. Adding a type, such as `DECIMAL(n, m)` is boiled down to adding tokens for that type, parsing that type and adding it to this enum.
-#[derive(Debug, PartialEq)]
-pub enum DBType {
-    Int,
-    Varchar(usize),
-    Bool,
-}
-
-/// A column can be limited to a domain of values, which is defined by constraints on that column. `PrimaryKey` and `NotNull` constraints have no additional info, while the `Check` constraints has an additional argument – the expression which every table row must satisfy.
-#[derive(Debug, PartialEq)]
-pub enum Constraint {
-    NotNull,
-    PrimaryKey,
-    Check(Expression)
-}
-
-/// Binary and unary operators are defined as enums, where each enumeration constant represents one operator. Binary and unary operators are defined separately because a `-` (minus), for example can be in a binary operation: `5 - 4`, as well as in a unary operation: `-2`. `Asc` and `Desc` are `ORDER BY` operators that have the lowest operator precedence in any expression. While both unary and binary operators may be the exact same as tokens that represent them, it is important to make a distinction between them, as they are used in different contexts.
-#[derive(Debug, PartialEq)]
-pub enum BinaryOperator {
-    Plus,
-    Minus,
-    Multiply,
-    Divide,
-    GreaterThan,
-    GreaterThanOrEqual,
-    LessThan,
-    LessThanOrEqual,
-    Equal,
-    NotEqual,
-    And,
-    Or,
-}
-
-/// Binary and unary operators are defined as enums, where each enumeration constant represents one operator. Binary and unary operators are defined separately because a `-` (minus), for example can be in a binary operation: `5 - 4`, as well as in a unary operation: `-2`. `Asc` and `Desc` are `ORDER BY` operators that have the lowest operator precedence in any expression. While both unary and binary operators may be the exact same as tokens that represent them, it is important to make a distinction between them, as they are used in different contexts.
-#[derive(Debug, PartialEq)]
-pub enum UnaryOperator {
-    Not,
-    Plus,
-    Minus,
-    Asc,
-    Desc,
-}
-
-// Example manual implementations for Display traits.
-// Automatic derivation of those traits can be done, but the actual printing
-// will be the same as in Debug prints which is not useful
-// when printing to the end user.
-
-impl Display for UnaryOperator {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            UnaryOperator::Minus => write!(f, "-"),
-            UnaryOperator::Plus => write!(f, "+"),
-            UnaryOperator::Desc => write!(f, "DESC"),
-            UnaryOperator::Asc => write!(f, "ASC"),
-            UnaryOperator::Not => write!(f, "NOT"),
-        }
-    }
-}
-
-impl Display for BinaryOperator {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BinaryOperator::GreaterThan => write!(f, ">"),
-            BinaryOperator::GreaterThanOrEqual => write!(f, ">="),
-            BinaryOperator::LessThan => write!(f, "<"),
-            BinaryOperator::LessThanOrEqual => write!(f, "<="),
-            BinaryOperator::Equal => write!(f, "="),
-            BinaryOperator::NotEqual => write!(f, "!="),
-            BinaryOperator::Multiply => write!(f, "*"),
-            BinaryOperator::Divide => write!(f, "/"),
-            BinaryOperator::Minus => write!(f, "-"),
-            BinaryOperator::Plus => write!(f, "+"),
-            BinaryOperator::And => write!(f, "AND"),
-            BinaryOperator::Or => write!(f, "OR"),
-        }
-    }
-}
-
-impl Display for Expression {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Expression::BinaryOperation { left_operand, operator, right_operand } => {
-                write!(f, "({:?} {:?} {:?})", left_operand, operator, right_operand)
-            }
-            Expression::UnaryOperation { operand, operator } => {
-                write!(f, "({:?} {:?})", operator, operand)
-            }
-            Expression::Number(num) => write!(f, "{num}"),
-            Expression::Identifier(iden) => write!(f, "{}", iden),
-            Expression::String(str) => write!(f, "\"{}\"", str),
-            Expression::Bool(b) => write!(f, "{}", b)
-        }
-    }
+use std::fmt::{Debug, Display, Formatter};
+use crate::formatter::SqlFormatter;
+
+/// The main entity of the whole parser. `Statement` is implemented as an enumeration because adding functionality is as easy as adding an enumeration constant and implementing functionality for that enumeration constant (implementation in the database command interpreter, which is not a part of this project). Parsing any correct `SELECT` or `CREATE`  (or `UPDATE`, `INSERT INTO`, ... hypothetically) statement should be turned into an instance of this enumeration. Ultimately, your main parser function (something like `build_statement(query: &str) -> Statement`) should return this enumeration.
+///
+/// The `SELECT` statement has thirteen components:
+/// 1. `distinct` – Whether the query was written as `SELECT DISTINCT`, which removes duplicate rows from the result.
+/// 2. `columns` – A vector of columns from the selected table that the database should return.
+/// 3. `wildcard_except` – The columns left out of a `*` projection with `* EXCEPT (a, b)`. It is empty unless the query selects `*`, and is only accepted by dialects that support it (BigQuery, DuckDB).
+/// 4. `wildcard_replace` – The `(expression, column)` pairs of `* REPLACE (expression AS column)`, which return the expression in place of the column. Like `wildcard_except`, it only follows `*` and is dialect-specific.
+/// 5. `from` – The table that is being queried, together with the alias it may be given (`FROM users u`), which qualified columns like `u.name` can refer to.
+/// 6. `joins` – The `JOIN` clauses following the `FROM` table, in the order they were written. Each `Join` names the joined table, the kind of join and its `ON` condition.
+/// 7. `where` – A single expression that is the actual filter for the database query. It is wrapped in an `Option` because not every `SELECT` query contains a filter. The actual name is `r#where` because in Rust, `where` is a reserved keyword, and the prefix `r#` means: interpret this token as a raw string, do not check for keyword matches.
+/// 8. `groupby` – A vector of grouping keys from the `GROUP BY` clause, empty when the query is not grouped. Like the ORDER BY keys, a grouping key can be any expression.
+/// 9. `having` – An optional filter applied to the groups, after grouping. It can only be written together with a `GROUP BY` clause.
+/// 10. `windows` – The named windows of the `WINDOW` clause, as `(name, definition)` pairs in the order they were written. Window functions refer to them with `OVER name`.
+/// 11. `orderby` – A vector of `OrderByItem`s that define how should the data be ordered. A vector is needed because the data can be ordered by the first column, and then all data that has the same first column can be ordered by the second column, ... Also, the data can be ordered not simply by columns, but by complex expressions as well.
+/// 12. `limit` – The optional `LIMIT` expression, the maximum number of rows to return. It is a number, except under the PostgreSQL dialect, where any expression is accepted and left for the database to evaluate. A negative number like `LIMIT -5` is always rejected, since it can never be a row count.
+/// 13. `offset` – The optional `OFFSET` expression, the number of rows to skip before returning any. Like `limit`, it cannot be a negative number. When both are present, `LIMIT` has to be written first.
+///
+/// The `CREATE TABLE` statement has five components:
+/// 1. `table_name` – A simple string, the name of the table.
+/// 2. `temporary` – Whether the statement was written as `CREATE TEMPORARY TABLE` (or `CREATE TEMP TABLE`).
+/// 3. `column_list` – A vector of table column types, where each table column contains the definition of one column.
+/// 4. `table_constraints` – The constraints written as items of their own in the column list, like `PRIMARY KEY (a, b)` or `FOREIGN KEY (a) REFERENCES b(id)`, which can span several columns. They are kept apart from `column_list` and may appear between column definitions.
+/// 5. `options` – The MySQL table options following the column list, like `ENGINE=InnoDB`, as `(name, value)` pairs. It is empty in every other dialect.
+///
+/// The `UPDATE` statement has three components:
+/// 1. `table_name` – A simple string, the name of the table being updated.
+/// 2. `assignments` – A vector of `(column, value)` pairs from the `SET` clause, in the order they were written.
+/// 3. `where` – An optional `WhereClause`: either a filter expression, exactly like in the `SELECT` statement, or `WHERE CURRENT OF cursor` for a positioned update.
+///
+/// The `DROP TABLE` statement has three components:
+/// 1. `table_names` – The names of the tables being dropped, in the order they were written. `DROP TABLE a, b` drops several tables at once, so there is at least one name.
+/// 2. `if_exists` – Whether the statement was written as `DROP TABLE IF EXISTS`, meaning a missing table is not an error.
+/// 3. `drop_behavior` – The optional trailing `CASCADE` or `RESTRICT`, deciding whether objects that depend on the tables (views, foreign keys) are dropped along with them or make the statement fail. It is `None` when neither was written, which databases treat as `RESTRICT`.
+///
+/// The `ALTER TABLE` statement has two components:
+/// 1. `table_name` – A simple string, the name of the table being altered.
+/// 2. `action` – What should be changed about the table. Actions are defined in the `AlterAction` enum.
+///
+/// The `INSERT INTO` statement has four components:
+/// 1. `table_name` – A simple string, the name of the table rows are inserted into.
+/// 2. `columns` – The explicit column list, empty when the statement does not name the columns.
+/// 3. `source` – The values of the new row(s): the rows of a `VALUES` clause, or the MySQL `SET a = 1, b = 2` form. Sources are defined in the `InsertSource` enum.
+/// 4. `returning` – The `RETURNING` list, parsed like the columns of a `SELECT` (`*`, or expressions with optional aliases). It is empty when there is no `RETURNING` clause.
+///
+/// A set operation, like `SELECT a FROM t1 UNION SELECT a FROM t2`, has four components:
+/// 1. `left` – The query on the left of the operator, either a `SELECT` or, in a chain like `q1 UNION q2 UNION q3`, the set operation before it (operations are grouped from the left).
+/// 2. `op` – Which set operation combines the two queries. Only `UNION` is supported for now.
+/// 3. `all` – Whether the operation was written with `ALL`, which keeps duplicate rows instead of removing them.
+/// 4. `right` – The `SELECT` on the right of the operator. Only the last `SELECT` of a chain may have `ORDER BY`, `LIMIT` or `OFFSET`, which apply to the combined result.
+///
+/// The `FETCH` and `MOVE` cursor statements have two components each:
+/// 1. `direction` – Which rows to fetch, or how far to move the cursor, as a `FetchDirection` (`NEXT`, `PRIOR`, `ABSOLUTE 5`, a plain count like `10`, ...). It is `None` when no direction was written, which databases treat as `NEXT`.
+/// 2. `cursor` – A simple string, the name of the cursor. The `FROM` or `IN` before it is optional and not recorded.
+///
+/// `MOVE` repositions the cursor exactly like `FETCH` would, without returning any rows.
+///
+/// Examples:
+///
+/// ---
+/// ```sql
+/// SELECT name, surname FROM users;
+/// ```
+/// is a `SELECT` statement that,  when parsed, looks like this:
+/// ```rust
+/// Statement::Select {
+///     distinct: false,
+///     columns: vec![
+///         Expression::Identifier("name".to_string()),
+///         Expression:Identifier("surname".to_string())
+///     ],
+///     wildcard_except: vec![],
+///     wildcard_replace: vec![],
+///     from: TableRef { name: "users".to_string(), alias: None },
+///     joins: vec![],
+///     r#where: None,
+///     groupby: vec![],
+///     having: None,
+///     windows: vec![],
+///     orderby: vec![],
+///     limit: None,
+///     offset: None,
+/// }
+/// ```
+/// ---
+/// ```sql
+/// SELECT age * 5, 'this is a string' FROM users;
+/// ```
+/// is a `SELECT` statement that,  when parsed, looks like this:
+/// ```rust
+/// Statement::Select {
+///     distinct: false,
+///     columns: vec![
+///         Expression::BinaryOperation {
+///             left_operand: Box::new(Expression::Identifier("age".to_string())),
+///             operator: BinaryOperator::Multiply,
+///             right_operand: Box::new(Expression::Number(5)),
+///         },
+///         Expression::String("this is a string".to_string()),
+///     ],
+///     wildcard_except: vec![],
+///     wildcard_replace: vec![],
+///     from: TableRef { name: "users".to_string(), alias: None },
+///     joins: vec![],
+///     r#where: None,
+///     groupby: vec![],
+///     having: None,
+///     windows: vec![],
+///     orderby: vec![],
+///     limit: None,
+///     offset: None,
+/// }
+/// ```
+/// ---
+/// ```sql
+/// SELECT name, surname FROM users WHERE name = \"Voldemort\" AND surname = 'Riddle';
+/// ```
+/// is a  `SELECT` statement that, when parsed, looks like this:
+/// ```rust
+/// Statement::Select {
+///     distinct: false,
+///     columns: [
+///         Expression::Identifier("name".to_string()),
+///         Expression::Identifier("surname".to_string()),
+///     ],
+///     wildcard_except: vec![],
+///     wildcard_replace: vec![],
+///     from: TableRef { name: "users".to_string(), alias: None },
+///     joins: vec![],
+///     r#where: Some(
+///         Expression::BinaryOperation {
+///             left_operand: Box::new(Expression::BinaryOperation {
+///                 left_operand: Box::new(Expression::Identifier("name".to_string())),
+///                 operand: BinaryOperator::Equals,
+///                 right_operand: Box::new(Expression::String("Voldemort".to_string())),
+///             }),
+///             operand: BinaryOperator::And,
+///             right_operand: Box::new(Expression::BinaryOperation {
+///                 left_operand: Box::new(Expression::Identifier("surname".to_string())),
+///                 operand: BinaryOperator::Equals,
+///                 right_operand: Box::new(Expression::String("Riddle".to_string())),
+///             }),
+///         },
+///     ),
+///     groupby: vec![],
+///     having: None,
+///     windows: vec![],
+///     orderby: vec![],
+///     limit: None,
+///     offset: None,
+/// }
+/// ```
+///  ---
+/// ```sql
+/// SELECT id, salary FROM users ORDER BY salary - 2 * 10 ASC, id DESC;
+/// ```
+/// is a  `SELECT` statement that, when parsed, looks like this:
+/// ```rust
+/// Statement::Select {
+///     distinct: false,
+///     columns: vec![
+///         Expression::Identifier("id".to_string()),
+///         Expression::Identifier("salary".to_string()),
+///     ],
+///     wildcard_except: vec![],
+///     wildcard_replace: vec![],
+///     from: TableRef { name: "users".to_string(), alias: None },
+///     joins: vec![],
+///     r#where: None,
+///     groupby: vec![],
+///     having: None,
+///     windows: vec![],
+///     orderby: vec![
+///         OrderByItem {
+///             expr: Expression::BinaryOperation {
+///                 left_operand: Box::new(Expression::Identifier("salary".to_string())),
+///                 operator: BinaryOperator::Minus,
+///                 right_operand: Box::new(Expression::BinaryOperation {
+///                     left_operand: Box::new(Expression::Number(2)),
+///                     operator: BinaryOperator::Multiply,
+///                     right_operand: Box::new(Expression::Number(10)),
+///                 }),
+///             },
+///             direction: Some(SortDirection::Asc),
+///         },
+///         OrderByItem {
+///             expr: Expression::Identifier("id".to_string()),
+///             direction: Some(SortDirection::Desc),
+///         },
+///     ],
+///     limit: None,
+///     offset: None,
+/// }
+/// ```
+///  ---
+/// ```sql
+/// SELECT id FROM registered_users WHERE password_encryption = TRUE ORDER BY id DESC;
+/// ```
+/// is a  `SELECT` statement that, when parsed, looks like this:
+/// ```rust
+/// Statement::Select {
+///     distinct: false,
+///     columns: vec![
+///         Expression::Identifier("id".to_string())
+///     ],
+///     wildcard_except: vec![],
+///     wildcard_replace: vec![],
+///     from: TableRef { name: "registered_users".to_string(), alias: None },
+///     joins: vec![],
+///     r#where: Some(
+///         Expression::BinaryOperation {
+///             left_operand: Box::new(Expression::Identifier("password_encryption".to_string())),
+///             operator: BinaryOperator::Equals,
+///             right_operand: Box::new(Expression::Bool(true))
+///         }
+///     ),
+///     groupby: vec![],
+///     having: None,
+///     windows: vec![],
+///     orderby: vec![
+///         OrderByItem {
+///             expr: Expression::Identifier("id".to_string()),
+///             direction: Some(SortDirection::Desc)
+///         }
+///     ],
+///     limit: None,
+///     offset: None,
+/// }
+/// ```
+/// ---
+/// ```sql
+/// CREATE TABLE simple_table(
+///     int_col INT,
+///     string_col VARCHAR(255),
+///     bool_col BOOL
+/// );
+/// ```
+/// is a  `CREATE TABLE` statement that, when parsed, looks like this:
+/// ```rust
+/// Statement::CreateTable {
+///     table_name: "simple_table".to_string(),
+///     temporary: false,
+///     column_list: vec![
+///         TableColumn {
+///             column_name: "int_col".to_string(),
+///             column_type: DBType::Int,
+///             constraints: vec![],
+///             comment: None,
+///         },
+///         TableColumn {
+///             column_name: "string_col".to_string(),
+///             column_type: DBType::Varchar(255),
+///             constraints: vec![],
+///             comment: None,
+///         },
+///         TableColumn {
+///             column_name: "bool_col".to_string(),
+///             column_type: DBType::Bool,
+///             constraints: vec![],
+///             comment: None,
+///         },
+///     ],
+///     table_constraints: vec![],
+///     options: vec![],
+/// }
+/// ```
+/// ---
+/// ```sql
+/// CREATE TABLE complex_table(
+///     id INT PRIMARY KEY,
+///     email VARCHAR(255) NOT NULL,
+///     is_junior BOOL,
+///     age INT CHECK(age >= 18) CHECK(age <= 65)
+/// );
+/// ```
+/// is a  `CREATE TABLE` statement that, when parsed, looks like this:
+/// ```rust
+/// Statement::CreateTable {
+///     table_name: Expression::Identifier("complex_table".to_string()),
+///     temporary: false,
+///     column_list: vec![
+///         TableColumn {
+///             column_name: Expression::Identifier("id".to_string()),
+///             column_type: DBType::Int,
+///             constraints: vec![
+///                 Constraint::PrimaryKey,
+///             ],
+///             comment: None,
+///         },
+///         TableColumn {
+///             column_name: Expression::Identifier("email".to_string()),
+///             column_type: DBType::Varchar(255),
+///             constraints: vec![
+///                 Constraint::NotNull,
+///             ],
+///             comment: None,
+///         },
+///         TableColumn {
+///             column_name: Expression::Identifier("is_junior".to_string()),
+///             column_type: DBType::Bool,
+///             constraints: vec![],
+///             comment: None,
+///         },
+///         TableColumn {
+///             column_name: Expression::Identifier("age".to_string()),
+///             column_type: DBType::Int,
+///             constraints: vec![
+///                 Constraint::Check(Expression::BinaryOperation {
+///                     left_operand: Box::new(Expression::Identifier("age".to_string())),
+///                     operator: BinaryOperator::GreaterThanOrEqual,
+///                     right_operand: Box::new(Expression::Number(18)),
+///                 }),
+///                 Constraint::Check(Expression::BinaryOperation {
+///                     left_operand: Box::new(Expression::Identifier("age".to_string())),
+///                     operator: BinaryOperator::LessThanOrEqual,
+///                     right_operand: Box::new(Expression::Number(65)),
+///                 }),
+///             ],
+///             comment: None,
+///         },
+///     ],
+///     table_constraints: vec![],
+///     options: vec![],
+/// }
+/// ```
+/// ---
+/// ```sql
+/// UPDATE users SET name = 'bob', age = 30 WHERE id = 1;
+/// ```
+/// is an `UPDATE` statement that, when parsed, looks like this:
+/// ```rust
+/// Statement::Update {
+///     table_name: "users".to_string(),
+///     assignments: vec![
+///         ("name".to_string(), Expression::String("bob".to_string())),
+///         ("age".to_string(), Expression::Number(30)),
+///     ],
+///     r#where: Some(WhereClause::Expression(
+///         Expression::BinaryOperation {
+///             left_operand: Box::new(Expression::Identifier("id".to_string())),
+///             operator: BinaryOperator::Equal,
+///             right_operand: Box::new(Expression::Number(1)),
+///         }
+///     )),
+/// }
+/// ```
+/// ---
+/// ```sql
+/// SELECT salary WHERE salary > 1000;
+/// ```
+/// is a string, that, the parser should throw an error to the user when it encounters it (no `FROM` clause).
+///
+/// ---
+/// ```sql
+/// CREATE TABLE work_hours(num_hours INT)
+/// ```
+/// is a string, that, the parser should throw an error to the user when it encounters it (no semicolon at the end), unless the statement is parsed on its own with the lenient `sql_parser::parse`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// SELECT has far more clauses than the other statements, but a statement is parsed one at a time,
+// so boxing its fields would only make the variant harder to construct and match on
+#[allow(clippy::large_enum_variant)]
+pub enum Statement {
+    Select {
+        distinct: bool,
+        columns: Vec<Expression>,
+        wildcard_except: Vec<String>,
+        wildcard_replace: Vec<(Expression, String)>,
+        from: TableRef,
+        joins: Vec<Join>,
+        r#where: Option<Expression>,
+        groupby: Vec<Expression>,
+        having: Option<Expression>,
+        windows: Vec<(String, WindowSpec)>,
+        orderby: Vec<OrderByItem>,
+        limit: Option<Expression>,
+        offset: Option<Expression>,
+    },
+    CreateTable {
+        table_name: String,
+        temporary: bool,
+        column_list: Vec<TableColumn>,
+        table_constraints: Vec<TableConstraint>,
+        options: Vec<(String, String)>,
+    },
+    Update {
+        table_name: String,
+        assignments: Vec<(String, Expression)>,
+        r#where: Option<WhereClause>,
+    },
+    DropTable {
+        table_names: Vec<String>,
+        if_exists: bool,
+        drop_behavior: Option<DropBehavior>,
+    },
+    AlterTable {
+        table_name: String,
+        action: AlterAction,
+    },
+    Insert {
+        table_name: String,
+        columns: Vec<String>,
+        source: InsertSource,
+        returning: Vec<Expression>,
+    },
+    SetOperation {
+        left: Box<Statement>,
+        op: SetOp,
+        all: bool,
+        right: Box<Statement>,
+    },
+    Fetch {
+        direction: Option<FetchDirection>,
+        cursor: String,
+    },
+    Move {
+        direction: Option<FetchDirection>,
+        cursor: String,
+    },
+}
+
+impl Statement {
+    /// Replaces literal values anywhere in the statement with the expression `f` returns for them
+    /// `f` is called on every literal (numbers, strings, booleans, `NULL` and typed literals such as `DATE '2020-01-01'`) in every clause, including the `DEFAULT` and `CHECK` expressions of column definitions and both sides of a `UNION`. Returning `None` keeps the literal as it is. The returned expression is not visited again, so it may itself be a literal.
+    /// This is the building block for parameterizing a query, by replacing each literal with a placeholder while collecting the original values, and for redacting values before logging a statement, e.g. `statement.rewrite_literals(|e| matches!(e, Expression::String(_)).then(|| Expression::String("***".to_string())))`.
+    pub fn rewrite_literals(&mut self, mut f: impl FnMut(&Expression) -> Option<Expression>) {
+        self.map_expressions(&mut |expr: &mut Expression| {
+            if expr.is_literal() {
+                if let Some(replacement) = f(expr) {
+                    *expr = replacement;
+                }
+            }
+        });
+    }
+
+    /// Renames the table alias `from` to `to`, both where the alias is given in a FROM or JOIN clause and in every column qualified with it
+    /// Renaming `u` to `usr` turns `SELECT u.id FROM users u` into `SELECT usr.id FROM users AS usr`. Qualifiers are compared exactly, so a table name used as a qualifier (`users.id`) is renamed as well when it matches. Subqueries are renamed together with the query around them, since their columns may refer to its tables.
+    pub fn rename_alias(&mut self, from: &str, to: &str) {
+        self.rename_table_aliases(from, to);
+        self.map_expressions(&mut |expr: &mut Expression| match expr {
+            Expression::QualifiedIdentifier { qualifier, .. } if qualifier == from => *qualifier = to.to_string(),
+            Expression::Subquery(subquery) | Expression::InSubquery { subquery, .. } | Expression::Exists { subquery, .. } => {
+                subquery.rename_table_aliases(from, to)
+            }
+            _ => {}
+        });
+    }
+
+    /// Renames the alias `from` to `to` in the FROM and JOIN clauses, leaving expressions alone
+    fn rename_table_aliases(&mut self, from: &str, to: &str) {
+        match self {
+            Statement::Select { from: table, joins, .. } => {
+                for table in std::iter::once(table).chain(joins.iter_mut().map(|join| &mut join.table)) {
+                    if table.alias.as_deref() == Some(from) {
+                        table.alias = Some(to.to_string());
+                    }
+                }
+            }
+            Statement::SetOperation { left, right, .. } => {
+                left.rename_table_aliases(from, to);
+                right.rename_table_aliases(from, to);
+            }
+            _ => {}
+        }
+    }
+
+    /// Calls `Expression::map` with `f` on every expression in the statement
+    fn map_expressions(&mut self, f: &mut impl FnMut(&mut Expression)) {
+        match self {
+            Statement::Select { columns, wildcard_replace, joins, r#where, groupby, having, windows, orderby, limit, offset, .. } => {
+                columns.iter_mut().for_each(|expr| expr.map(f));
+                wildcard_replace.iter_mut().for_each(|(expr, _)| expr.map(f));
+                joins.iter_mut().for_each(|join| join.on.map(f));
+                r#where.iter_mut().for_each(|expr| expr.map(f));
+                groupby.iter_mut().for_each(|expr| expr.map(f));
+                having.iter_mut().for_each(|expr| expr.map(f));
+                windows.iter_mut().for_each(|(_, spec)| spec.map(f));
+                orderby.iter_mut().for_each(|item| item.expr.map(f));
+                limit.iter_mut().chain(offset.iter_mut()).for_each(|expr| expr.map(f));
+            }
+            Statement::CreateTable { column_list, .. } => column_list.iter_mut().for_each(|column| column.map_expressions(f)),
+            Statement::AlterTable { action: AlterAction::AddColumn(column), .. } => column.map_expressions(f),
+            Statement::Update { assignments, r#where, .. } => {
+                assignments.iter_mut().for_each(|(_, expr)| expr.map(f));
+                if let Some(WhereClause::Expression(expr)) = r#where {
+                    expr.map(f);
+                }
+            }
+            Statement::Insert { source, returning, .. } => {
+                match source {
+                    InsertSource::Values(rows) => rows.iter_mut().flatten().for_each(|expr| expr.map(f)),
+                    InsertSource::Assignments(assignments) => assignments.iter_mut().for_each(|(_, expr)| expr.map(f)),
+                }
+                returning.iter_mut().for_each(|expr| expr.map(f));
+            }
+            Statement::SetOperation { left, right, .. } => {
+                left.map_expressions(f);
+                right.map_expressions(f);
+            }
+            Statement::AlterTable { .. } | Statement::DropTable { .. } | Statement::Fetch { .. } | Statement::Move { .. } => {}
+        }
+    }
+}
+
+/// The main entity of the expression parser. The Expression enum is structured like this, where an expression can contain another expression. This naturally allows us to represent complex expressions as trees. `Box<T>` smart pointers are used on unary and binary types of expressions because the compiler needs to know the size of the enum at compile time which is impossible when an enum contains itself (infinite size).
+///
+/// An expression can be:
+/// * complex - a number of other expressions (tree-like structure, unary and binary operations)
+/// * a single number (a whole number, or a floating-point number when written with a decimal point, like `9.99`)
+/// * a single identifier (like a variable 'x'). An identifier written in double quotes or backticks, like `"order"`, keeps its exact spelling and is never a keyword.
+/// * a qualified identifier – a column of a specific table, `users.name` or `u.name` with a table alias. A qualified wildcard `u.*` has the name `*`.
+/// * a single string (when doing parsing of WHERE statements that do operations with strings, strings must be in single quotes, `'text'`, since `"text"` is a quoted identifier)
+/// * a boolean (only true or false)
+/// * the `NULL` literal, as a value like in `SELECT NULL` or `x = NULL`. Checking for null is the separate `IS NULL` expression below.
+/// * a pattern match – `name LIKE 'A%'`, or `name NOT LIKE 'A%'` which sets `negated`. The pattern is an ordinary expression, usually a string.
+/// * a null check – `email IS NULL`, or `email IS NOT NULL` which sets `negated`
+/// * a null-safe comparison – `a IS DISTINCT FROM b`, or `a IS NOT DISTINCT FROM b` which sets `negated`. Unlike `<>` and `=`, two NULLs compare as not distinct.
+/// * a range test – `age BETWEEN 18 AND 65`, or `age NOT BETWEEN 18 AND 65` which sets `negated`. Both bounds are inclusive.
+/// * a list membership test – `status IN ('active', 'pending')`, or `status NOT IN (...)` which sets `negated`. The list holds at least one expression.
+/// * a subquery membership test – `dept_id IN (SELECT id FROM depts)`, or `NOT IN` which sets `negated`. It is kept apart from a list membership test, because `x IN ((SELECT ...))` is a list holding a single scalar subquery.
+/// * a scalar subquery – a parenthesized `SELECT` used as a value, like `x = (SELECT MAX(y) FROM t)`. The database requires it to return at most one row with one column.
+/// * a function call – a name followed by a parenthesized, comma-separated argument list, like `UPPER(name)`, `CONCAT_WS(',', a, b)` or `NOW()`. In `COUNT(*)` the lone `*` argument is the identifier `*`, and an aggregate over distinct values like `COUNT(DISTINCT user_id)` sets `distinct`.
+/// * an aliased expression – `price * quantity AS total`, or `price * quantity total` without the `AS`. Aliases are only valid in a projection list (`SELECT` columns, `RETURNING`), never inside another expression.
+/// * a typed literal – a type keyword directly followed by a string, like `DATE '2020-01-01'` or `TIMESTAMP '2020-01-01 00:00:00'`. The string is kept as written, validating its contents is left to the database.
+/// * a window function – a function call followed by `OVER` and a `Window`, like `rank() OVER w` or `SUM(x) OVER (PARTITION BY y ORDER BY z)`
+/// * a conditional – `CASE WHEN x > 0 THEN 'pos' ELSE 'neg' END`, or the simple form `CASE x WHEN 1 THEN 'one' END` which compares `operand` against each WHEN value. There is at least one WHEN clause, and a CASE without ELSE yields NULL when nothing matches.
+/// * an existence test – `EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)`, or `NOT EXISTS (...)` which sets `negated`. The subquery is a `Select` or a `UNION` of them, written without a semicolon of its own.
+///
+/// Examples:
+///
+/// ---
+/// ```
+/// (13 + 7) - 4
+/// ```
+/// is an expression that contains two expressions:
+/// 1. `(13 + 7)` which is
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Box::new(Expression::Number(13)),
+///     operator: BinaryOperator::Plus,
+///     right_operand: Box::new(Expression::Number(7))
+/// }
+/// ```
+/// 2. `4` which is
+/// ```rust
+/// Expression::Number(4)
+/// ```
+/// Therefore, the whole expression after parsing should look like this:
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Expression::BinaryOperation {
+///         left_operand: Box::new(Expression::Number(13)),
+///         operator: BinaryOperator::Plus,
+///         right_operand: Box::new(Expression::Number(7))
+///     },
+///     operator: BinaryOperator::Minus,
+///     right_operand: Box::new(Expression::Number(4))
+/// }
+/// ```
+/// ---
+/// ```
+/// (5 - x) < (4 + y) OR name = "Donna"
+/// ```
+/// is an expression that contains five (three small and two combining) expressions:
+/// 1. `(5 - x)` which is
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Box::new(Expression::Number(5)),
+///     operator: BinaryOperator::Minus,
+///     right_operand: Box::new(Expression::Identifier("x".to_string())),
+/// }
+/// ```
+/// 2. `(4 - y)` which is
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Box::new(Expression::Number(4)),
+///     operator: BinaryOperator::Plus,
+///     right_operand: Box::new(Expression::Identifier("y".to_string()))
+/// }
+/// ```
+/// 3. `name = "Donna"` which is
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Box::new(Expression::Identifier("name".to_string())),
+///     operator: BinaryOperator::Equal,
+///     right_operand: Box::new(Expression::String("Donna".to_string()))
+/// }
+/// ```
+/// Therefore, the whole expression after parsing should look like this:
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Box::new(Expression::BinaryOperation {
+///         left_operand: Box::new(Expression::BinaryOperation {
+///             left_operand: Box::new(Expression::Number(5)),
+///             operator: BinaryOperator::Minus,
+///             right_operand: Box::new(Expression::Identifier("x".to_string()))
+///         }),
+///         operator: BinaryOperator::LessThan,
+///         right_operand: Box::new(Expression::BinaryOperation {
+///             left_operand: Box::new(Expression::Number(4)),
+///             operator: BinaryOperator::Plus,
+///             right_operand: Box::new(Expression::Identifier("y".to_string()))
+///         })
+///     }),
+///     operator: BinaryOperator::Or,
+///     right_operand: Box::new(Expression::BinaryOperation {
+///         left_operand: Box::new(Expression::Identifier("name".to_string())),
+///         operator: BinaryOperator::Equal,
+///         right_operand: Box::new(Expression::String("Donna".to_string()))
+///     })
+/// }
+/// ```
+/// ---
+/// ```
+/// NOT some_boolean = TRUE
+/// ```
+/// should look like this:
+/// ```rust
+/// Expression::BinaryOperation {
+///     left_operand: Box::new(Expression::UnaryOperation {
+///         left_operand: Box::new(Expression::Identifier("some_boolean".to_string())),
+///         operator: UnaryOperator::Not
+///     }),
+///     operator: BinaryOperator::Equal,
+///     right_operand: Box::new(Expression::Bool(true))
+/// }
+/// ```
+/// ---
+/// ```
+/// 5 * 3 - 4 + c / (13 -)
+/// ```
+/// is a string, that, the parser should throw an error to the user when it encounters it.
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Expression {
+    BinaryOperation {
+        left_operand: Box<Expression>,
+        operator: BinaryOperator,
+        right_operand: Box<Expression>,
+    },
+    UnaryOperation {
+        operand: Box<Expression>,
+        operator: UnaryOperator,
+    },
+    Number(u64),
+    Float(f64),
+    Bool(bool),
+    Null,
+    Identifier(String),
+    QualifiedIdentifier {
+        qualifier: String,
+        name: String,
+    },
+    String(String),
+    TypedLiteral {
+        ty: DBType,
+        value: String,
+    },
+    Like {
+        expr: Box<Expression>,
+        pattern: Box<Expression>,
+        negated: bool,
+    },
+    IsNull {
+        expr: Box<Expression>,
+        negated: bool,
+    },
+    IsDistinctFrom {
+        left: Box<Expression>,
+        right: Box<Expression>,
+        negated: bool,
+    },
+    Between {
+        expr: Box<Expression>,
+        low: Box<Expression>,
+        high: Box<Expression>,
+        negated: bool,
+    },
+    InList {
+        expr: Box<Expression>,
+        list: Vec<Expression>,
+        negated: bool,
+    },
+    FunctionCall {
+        name: String,
+        distinct: bool,
+        args: Vec<Expression>,
+    },
+    Aliased {
+        expr: Box<Expression>,
+        alias: String,
+    },
+    WindowFunction {
+        function: Box<Expression>,
+        window: Window,
+    },
+    Case {
+        operand: Option<Box<Expression>>,
+        when_clauses: Vec<(Expression, Expression)>,
+        else_clause: Option<Box<Expression>>,
+    },
+    Exists {
+        subquery: Box<Statement>,
+        negated: bool,
+    },
+    InSubquery {
+        expr: Box<Expression>,
+        subquery: Box<Statement>,
+        negated: bool,
+    },
+    Subquery(Box<Statement>),
+}
+
+impl Expression {
+    /// Number of arguments a function call was written with, or 0 for any other expression
+    /// Variadic functions such as `CONCAT(a, b, c)` or `CONCAT_WS(',', a, b)` have no fixed arity, so this is simply the length of the argument list.
+    pub fn arg_count(&self) -> usize {
+        match self {
+            Expression::FunctionCall { args, .. } => args.len(),
+            _ => 0,
+        }
+    }
+
+    /// Whether the expression is a literal value: a number, string, boolean, `NULL` or typed literal
+    pub fn is_literal(&self) -> bool {
+        matches!(
+            self,
+            Expression::Number(_)
+                | Expression::Float(_)
+                | Expression::String(_)
+                | Expression::Bool(_)
+                | Expression::Null
+                | Expression::TypedLiteral { .. }
+        )
+    }
+
+    /// A best-effort guess at the type of the value the expression produces, without knowing the schema
+    /// Literals have the obvious type (`1` is `Int`, `1.5` is `Float`, `'a'` is `Text`, `DATE '2020-01-01'` is `Date`), and comparisons, `AND`/`OR`/`NOT` and predicates such as `LIKE`, `IN` or `EXISTS` are `Bool`. `||` concatenation is `Text`, and a sign or an alias keeps the type of its operand. Anything that depends on a column or a function, including arithmetic, is `None`, as is `NULL`.
+    pub fn type_hint(&self) -> Option<DBType> {
+        match self {
+            Expression::Number(_) => Some(DBType::Int),
+            Expression::Float(_) => Some(DBType::Float),
+            Expression::String(_) => Some(DBType::Text),
+            Expression::Bool(_) => Some(DBType::Bool),
+            Expression::TypedLiteral { ty, .. } => Some(ty.clone()),
+            Expression::BinaryOperation { operator, .. } => match operator {
+                BinaryOperator::GreaterThan
+                | BinaryOperator::GreaterThanOrEqual
+                | BinaryOperator::LessThan
+                | BinaryOperator::LessThanOrEqual
+                | BinaryOperator::Equal
+                | BinaryOperator::NotEqual
+                | BinaryOperator::NullSafeEqual
+                | BinaryOperator::And
+                | BinaryOperator::Or => Some(DBType::Bool),
+                BinaryOperator::Concat => Some(DBType::Text),
+                BinaryOperator::Plus | BinaryOperator::Minus | BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => None,
+            },
+            Expression::UnaryOperation { operator: UnaryOperator::Not, .. } => Some(DBType::Bool),
+            Expression::UnaryOperation { operand, .. } => operand.type_hint(),
+            Expression::Like { .. }
+            | Expression::IsNull { .. }
+            | Expression::IsDistinctFrom { .. }
+            | Expression::Between { .. }
+            | Expression::InList { .. }
+            | Expression::InSubquery { .. }
+            | Expression::Exists { .. } => Some(DBType::Bool),
+            Expression::Aliased { expr, .. } => expr.type_hint(),
+            Expression::Null
+            | Expression::Identifier(_)
+            | Expression::QualifiedIdentifier { .. }
+            | Expression::FunctionCall { .. }
+            | Expression::WindowFunction { .. }
+            | Expression::Case { .. }
+            | Expression::Subquery(_) => None,
+        }
+    }
+
+    /// Calls `f` on every expression in the tree, children before their parent, so `f` can rewrite each node in place
+    /// The expressions of an inline window specification (`PARTITION BY`, `ORDER BY` and frame bounds) are visited as children of the window function.
+    /// The expressions of a subquery are visited as children of the expression containing it.
+    /// For example, renaming a column is `expr.map(&mut |e| if *e == Expression::Identifier("a".to_string()) { *e = Expression::Identifier("b".to_string()) })`.
+    pub fn map(&mut self, f: &mut impl FnMut(&mut Expression)) {
+        match self {
+            Expression::BinaryOperation { left_operand, right_operand, .. } => {
+                left_operand.map(f);
+                right_operand.map(f);
+            }
+            Expression::UnaryOperation { operand, .. } => operand.map(f),
+            Expression::Like { expr, pattern, .. } => {
+                expr.map(f);
+                pattern.map(f);
+            }
+            Expression::IsNull { expr, .. } => expr.map(f),
+            Expression::IsDistinctFrom { left, right, .. } => {
+                left.map(f);
+                right.map(f);
+            }
+            Expression::Between { expr, low, high, .. } => {
+                expr.map(f);
+                low.map(f);
+                high.map(f);
+            }
+            Expression::InList { expr, list, .. } => {
+                expr.map(f);
+                list.iter_mut().for_each(|item| item.map(f));
+            }
+            Expression::FunctionCall { args, .. } => args.iter_mut().for_each(|arg| arg.map(f)),
+            Expression::Aliased { expr, .. } => expr.map(f),
+            Expression::WindowFunction { function, window } => {
+                function.map(f);
+                if let Window::Spec(spec) = window {
+                    spec.map(f);
+                }
+            }
+            Expression::Case { operand, when_clauses, else_clause } => {
+                if let Some(operand) = operand {
+                    operand.map(f);
+                }
+                for (condition, result) in when_clauses {
+                    condition.map(f);
+                    result.map(f);
+                }
+                if let Some(else_clause) = else_clause {
+                    else_clause.map(f);
+                }
+            }
+            Expression::Exists { subquery, .. } | Expression::Subquery(subquery) => subquery.map_expressions(f),
+            Expression::InSubquery { expr, subquery, .. } => {
+                expr.map(f);
+                subquery.map_expressions(f);
+            }
+            Expression::Number(_)
+            | Expression::Float(_)
+            | Expression::Bool(_)
+            | Expression::Null
+            | Expression::Identifier(_)
+            | Expression::QualifiedIdentifier { .. }
+            | Expression::String(_)
+            | Expression::TypedLiteral { .. } => {}
+        }
+        f(self);
+    }
+
+    /// Flattens the expression into Reverse Polish Notation, operands before the operator applied to them
+    /// `a + b * c` becomes `[a, b, c, *, +]`, which a stack machine evaluates by pushing operands and letting each
+    /// operator pop its arguments. A function call pops `arg_count` arguments. Any other expression (`LIKE`, `CASE`,
+    /// `DISTINCT` aggregates, ...) is kept whole as a single operand, leaving its evaluation to the caller.
+    pub fn to_rpn(&self) -> Vec<RpnToken<'_>> {
+        let mut tokens = Vec::new();
+        self.write_rpn(&mut tokens);
+        tokens
+    }
+
+    fn write_rpn<'a>(&'a self, tokens: &mut Vec<RpnToken<'a>>) {
+        match self {
+            Expression::UnaryOperation { operand, operator } => {
+                operand.write_rpn(tokens);
+                tokens.push(RpnToken::UnaryOperator(operator));
+            }
+            Expression::BinaryOperation { left_operand, operator, right_operand } => {
+                left_operand.write_rpn(tokens);
+                right_operand.write_rpn(tokens);
+                tokens.push(RpnToken::BinaryOperator(operator));
+            }
+            Expression::FunctionCall { name, distinct: false, args } => {
+                for arg in args {
+                    arg.write_rpn(tokens);
+                }
+                tokens.push(RpnToken::Function { name, arg_count: args.len() });
+            }
+            operand => tokens.push(RpnToken::Operand(operand)),
+        }
+    }
+}
+
+/// One step of an expression in Reverse Polish Notation, as produced by `Expression::to_rpn`
+/// The tokens borrow from the expression they were made from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpnToken<'a> {
+    Operand(&'a Expression),
+    UnaryOperator(&'a UnaryOperator),
+    BinaryOperator(&'a BinaryOperator),
+    Function { name: &'a str, arg_count: usize },
+}
+
+/// The operator of a `Statement::SetOperation`, which combines the rows of two queries.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetOp {
+    Union,
+}
+
+/// One `JOIN` clause of a `SELECT`, like `JOIN orders o ON o.user_id = id`. `on` is the join condition, which is required for every join type.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Join {
+    pub table: TableRef,
+    pub join_type: JoinType,
+    pub on: Expression,
+}
+
+/// A reference to a table in a `FROM` or `JOIN` clause, with the optional alias it was given (`orders AS o` or `orders o`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableRef {
+    pub name: String,
+    pub alias: Option<String>,
+}
+
+/// The kind of a join. A plain `JOIN` is an `INNER JOIN`, and the `OUTER` in `LEFT OUTER JOIN` is optional, so `LEFT JOIN` and `LEFT OUTER JOIN` are both `Left`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+/// The window a window function is computed over. `Named` refers to a window defined in the `WINDOW` clause of the query (`OVER w`), while `Spec` is a specification written in place (`OVER (ORDER BY x)`). The specification is boxed so that it does not grow every `Expression`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Window {
+    Named(String),
+    Spec(Box<WindowSpec>),
+}
+
+/// The contents of a window definition, `(PARTITION BY ... ORDER BY ... frame)`. Every part is optional, so `OVER ()` is a window spanning the whole result.
+/// 1. `partition_by` – Expressions splitting the rows into independent partitions.
+/// 2. `order_by` – The order of the rows within a partition, with the same entries as the `ORDER BY` clause of a `SELECT`.
+/// 3. `frame` – Which rows around the current row the function sees, `None` when no frame clause was written.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowSpec {
+    pub partition_by: Vec<Expression>,
+    pub order_by: Vec<OrderByItem>,
+    pub frame: Option<WindowFrame>,
+}
+
+impl WindowSpec {
+    /// Calls `Expression::map` on the `PARTITION BY` and `ORDER BY` expressions and the frame bound offsets
+    fn map(&mut self, f: &mut impl FnMut(&mut Expression)) {
+        self.partition_by.iter_mut().for_each(|expr| expr.map(f));
+        self.order_by.iter_mut().for_each(|item| item.expr.map(f));
+        if let Some(frame) = &mut self.frame {
+            for bound in std::iter::once(&mut frame.start).chain(frame.end.as_mut()) {
+                if let FrameBound::Preceding(expr) | FrameBound::Following(expr) = bound {
+                    expr.map(f);
+                }
+            }
+        }
+    }
+}
+
+/// Where the rows of an `INSERT` come from. `Values` holds one vector of expressions per row of a `VALUES` clause, each as long as the column list when there is one. `Assignments` is MySQL's `INSERT INTO t SET a = 1, b = 2`, which inserts a single row and names each column next to its value like an `UPDATE`, so it is never combined with a column list.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InsertSource {
+    Values(Vec<Vec<Expression>>),
+    Assignments(Vec<(String, Expression)>),
+}
+
+/// A window frame such as `ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW`. `end` is `None` when only a start bound was written (`ROWS UNBOUNDED PRECEDING`), which databases read as ending at the current row. `exclude` is the optional `EXCLUDE` clause, which removes rows from the frame after its bounds have been applied.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowFrame {
+    pub units: FrameUnits,
+    pub start: FrameBound,
+    pub end: Option<FrameBound>,
+    pub exclude: Option<FrameExclusion>,
+}
+
+/// Whether the bounds of a window frame count physical rows (`ROWS`) or values of the ordering key (`RANGE`).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameUnits {
+    Rows,
+    Range,
+}
+
+/// One bound of a window frame. The offsets of `Preceding` and `Following` are expressions, usually numbers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(Expression),
+    CurrentRow,
+    Following(Expression),
+    UnboundedFollowing,
+}
+
+/// The `EXCLUDE` clause of a window frame: `EXCLUDE CURRENT ROW`, `EXCLUDE GROUP`, `EXCLUDE TIES` or `EXCLUDE NO OTHERS`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameExclusion {
+    CurrentRow,
+    Group,
+    Ties,
+    NoOthers,
+}
+
+/// The `WHERE` clause of a statement that can target rows either by a filter or by a cursor position. `Expression` is an ordinary filter, while `CurrentOf` (`WHERE CURRENT OF cursor`) targets the row the named cursor is currently positioned on. The two are kept apart because `CURRENT OF` is not an expression and cannot be combined with `AND`/`OR`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WhereClause {
+    Expression(Expression),
+    CurrentOf(String),
+}
+
+/// A structure containing a definition for one column, when creating a table.
+/// 1. `column_name` – A simple string, representing a name.
+/// 2. `column_type` – The type of the column. Types are defined in the `DBType` enum.
+/// 3.  `constraints` – A vector of constraints on the column. Types of constraints are defined in the `Constraint` enum. They are kept in the order they were written, e.g. `DEFAULT 0 NOT NULL CHECK (a >= 0)` gives `[Default, NotNull, Check]`, and the formatter writes them back in that order, so re-emitted SQL is stable. The `AutoIncrement` implied by a serial type comes before all of them.
+/// 4. `comment` – The MySQL `COMMENT 'text'` attached to the column, if any. It is only parsed under the MySQL dialect.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TableColumn {
+    pub column_name: String,
+    pub column_type: DBType,
+    pub constraints: Vec<Constraint>,
+    pub comment: Option<String>,
+}
+
+impl TableColumn {
+    /// Whether the column accepts null values. A column is non-nullable when it has a `NotNull` constraint, or a `PrimaryKey` or `AutoIncrement` constraint, since primary keys and serial columns are implicitly `NOT NULL`.
+    pub fn is_nullable(&self) -> bool {
+        !self
+            .constraints
+            .iter()
+            .any(|constraint| matches!(constraint, Constraint::NotNull | Constraint::PrimaryKey | Constraint::AutoIncrement))
+    }
+
+    /// Calls `Expression::map` on the `CHECK` and `DEFAULT` expressions of the column
+    fn map_expressions(&mut self, f: &mut impl FnMut(&mut Expression)) {
+        for constraint in &mut self.constraints {
+            if let Constraint::Check(expr) | Constraint::Default(expr) = constraint {
+                expr.map(f);
+            }
+        }
+    }
+}
+
+/// A constraint on a table as a whole rather than on one column. Either kind may be given a `name` by writing `CONSTRAINT name` in front of it.
+///
+/// `PrimaryKey` makes the listed columns, taken together, the primary key of the table, as in `PRIMARY KEY (a, b)`.
+///
+/// `ForeignKey` makes `columns` refer to `referenced_columns` of `table`, as in `FOREIGN KEY (a, b) REFERENCES t(x, y)`. Both lists have the same length, unless `referenced_columns` is empty because the statement left them out to refer to the primary key of `table`. The actions and match type mean the same as for the column-level `References` constraint.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TableConstraint {
+    PrimaryKey {
+        name: Option<String>,
+        columns: Vec<String>,
+    },
+    ForeignKey {
+        name: Option<String>,
+        columns: Vec<String>,
+        table: String,
+        referenced_columns: Vec<String>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+        match_type: Option<MatchType>,
+    },
+}
+
+/// What `DROP TABLE` does with objects that depend on the dropped tables: `CASCADE` drops them too, while `RESTRICT` refuses to drop a table that anything depends on.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropBehavior {
+    Cascade,
+    Restrict,
+}
+
+/// The direction of a `FETCH` or `MOVE`, relative to the current cursor position. `Absolute` and `Relative` name a single row, counting from the end when negative, while `Count` covers that many rows (backwards when negative) and `All` every remaining row.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FetchDirection {
+    Next,
+    Prior,
+    First,
+    Last,
+    Absolute(i64),
+    Relative(i64),
+    Count(i64),
+    All,
+}
+
+/// An `ALTER TABLE` statement performs exactly one of these actions. `AddColumn` carries the full definition of the new column, parsed exactly like a column in `CREATE TABLE` (constraints included). `AddConstraint` adds a table-level constraint, like `ADD PRIMARY KEY (a, b)` or `ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES b(id)`, parsed exactly like one written in `CREATE TABLE`. `DropConstraint` removes the constraint with the given name (`DROP CONSTRAINT fk`), and `DropPrimaryKey` is MySQL's `DROP PRIMARY KEY`, which needs no name since a table has at most one primary key.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterAction {
+    AddColumn(TableColumn),
+    AddConstraint(TableConstraint),
+    DropConstraint(String),
+    DropPrimaryKey,
+}
+
+/// A column in the database can be any of these types. The integer types (`Int`, `SmallInt`, `BigInt`), the floating-point types (`Float`, `Double`) and `Bool` (written `BOOL` or `BOOLEAN`) have no additional info, while the `Varchar(n)` type has an additional argument – the maximum length of the string. `Char(n)` holds strings of exactly `n` characters, and `Text` strings of any length. `Decimal` is an exact number with `precision` significant digits, `scale` of them after the decimal point; `DECIMAL(10)` has a scale of 0. Adding a type is boiled down to adding tokens for that type, parsing that type and adding it to this enum.
+///
+/// The temporal types `Date`, `Timestamp` and `Time` are used both for columns and in typed literals such as `DATE '2020-01-01'`. Time zones and fractional-second precision are not parsed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DBType {
+    Int,
+    SmallInt,
+    BigInt,
+    Float,
+    Double,
+    Decimal { precision: usize, scale: usize },
+    Varchar(usize),
+    Char(usize),
+    Text,
+    Bool,
+    Date,
+    Timestamp,
+    Time,
+}
+
+/// A column can be limited to a domain of values, which is defined by constraints on that column. `PrimaryKey`, `Unique` and `NotNull` constraints have no additional info, while the `Check` constraints has an additional argument – the expression which every table row must satisfy.
+///
+/// `Default` holds the expression a column takes when an `INSERT` leaves it out, like `DEFAULT 0` or `DEFAULT TRUE`.
+///
+/// `Unique` only forbids duplicate values, so unlike `PrimaryKey` it leaves the column nullable unless `NOT NULL` is written too.
+///
+/// `AutoIncrement` is never written as a constraint. It is implied by the PostgreSQL `SERIAL`, `SMALLSERIAL` and `BIGSERIAL` pseudo-types, which are parsed as `INT`, `SMALLINT` and `BIGINT` columns filled from a sequence.
+///
+/// The `References` constraint makes the column a foreign key into `table` (optionally naming the referenced `column`). It also records what should happen to the row when the referenced row is deleted or updated (`ON DELETE CASCADE`, `ON UPDATE SET NULL`, ...) and how composite keys containing nulls are matched (`MATCH FULL`). Clauses that were not written are `None`, leaving the choice of default behaviour to the database.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constraint {
+    NotNull,
+    PrimaryKey,
+    Unique,
+    AutoIncrement,
+    Check(Expression),
+    Default(Expression),
+    References {
+        table: String,
+        column: Option<String>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+        match_type: Option<MatchType>,
+    },
+}
+
+/// The action taken on a referencing row when the row it references is deleted or updated.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReferentialAction {
+    Cascade,
+    Restrict,
+    SetNull,
+    NoAction,
+}
+
+/// The `MATCH` mode of a foreign key, which decides how null values in the referencing columns are treated.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatchType {
+    Full,
+    Partial,
+    Simple,
+}
+
+/// Binary and unary operators are defined as enums, where each enumeration constant represents one operator. Binary and unary operators are defined separately because a `-` (minus), for example can be in a binary operation: `5 - 4`, as well as in a unary operation: `-2`. While both unary and binary operators may be the exact same as tokens that represent them, it is important to make a distinction between them, as they are used in different contexts.
+///
+/// `Concat` is string concatenation, which standard SQL writes as `a || b`. The MySQL dialect reads `||` as a logical OR instead, so there it parses as `Or`.
+/// `NullSafeEqual` is MySQL's `a <=> b`, an equality that is true when both sides are NULL.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryOperator {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    Modulo,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Equal,
+    NotEqual,
+    NullSafeEqual,
+    And,
+    Or,
+    Concat,
+}
+
+/// Binary and unary operators are defined as enums, where each enumeration constant represents one operator. Binary and unary operators are defined separately because a `-` (minus), for example can be in a binary operation: `5 - 4`, as well as in a unary operation: `-2`. While both unary and binary operators may be the exact same as tokens that represent them, it is important to make a distinction between them, as they are used in different contexts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnaryOperator {
+    Not,
+    Plus,
+    Minus,
+}
+
+/// One entry of an `ORDER BY` clause – the expression to sort by and the direction written after it. `direction` is `None` when neither `ASC` nor `DESC` was written, which databases treat as ascending. The direction is kept apart from the expression because it is not an operator: it can only appear at the very end of an `ORDER BY` entry.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderByItem {
+    pub expr: Expression,
+    pub direction: Option<SortDirection>,
+}
+
+impl OrderByItem {
+    /// Whether the entry sorts by a plain column reference (`ORDER BY b` or `ORDER BY t.b`) rather than a computed expression (`ORDER BY a + b`)
+    /// A downstream engine can use a simple column directly as a sort key, while a computed key has to be evaluated per row first.
+    pub fn is_simple_column(&self) -> bool {
+        matches!(
+            &self.expr,
+            Expression::Identifier(name) | Expression::QualifiedIdentifier { name, .. } if name != "*"
+        )
+    }
+}
+
+/// The direction of an `ORDER BY` entry.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+// Example manual implementations for Display traits.
+// Automatic derivation of those traits can be done, but the actual printing
+// will be the same as in Debug prints which is not useful
+// when printing to the end user.
+
+impl Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnaryOperator::Minus => write!(f, "-"),
+            UnaryOperator::Plus => write!(f, "+"),
+            UnaryOperator::Not => write!(f, "NOT"),
+        }
+    }
+}
+
+impl Display for SortDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortDirection::Asc => write!(f, "ASC"),
+            SortDirection::Desc => write!(f, "DESC"),
+        }
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryOperator::GreaterThan => write!(f, ">"),
+            BinaryOperator::GreaterThanOrEqual => write!(f, ">="),
+            BinaryOperator::LessThan => write!(f, "<"),
+            BinaryOperator::LessThanOrEqual => write!(f, "<="),
+            BinaryOperator::Equal => write!(f, "="),
+            BinaryOperator::NotEqual => write!(f, "!="),
+            BinaryOperator::NullSafeEqual => write!(f, "<=>"),
+            BinaryOperator::Multiply => write!(f, "*"),
+            BinaryOperator::Divide => write!(f, "/"),
+            BinaryOperator::Modulo => write!(f, "%"),
+            BinaryOperator::Minus => write!(f, "-"),
+            BinaryOperator::Plus => write!(f, "+"),
+            BinaryOperator::And => write!(f, "AND"),
+            BinaryOperator::Or => write!(f, "OR"),
+            BinaryOperator::Concat => write!(f, "||"),
+        }
+    }
+}
+
+impl Display for DBType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DBType::Int => write!(f, "INT"),
+            DBType::SmallInt => write!(f, "SMALLINT"),
+            DBType::BigInt => write!(f, "BIGINT"),
+            DBType::Float => write!(f, "FLOAT"),
+            DBType::Double => write!(f, "DOUBLE"),
+            DBType::Decimal { precision, scale } => write!(f, "DECIMAL({}, {})", precision, scale),
+            DBType::Varchar(length) => write!(f, "VARCHAR({})", length),
+            DBType::Char(length) => write!(f, "CHAR({})", length),
+            DBType::Text => write!(f, "TEXT"),
+            DBType::Bool => write!(f, "BOOL"),
+            DBType::Date => write!(f, "DATE"),
+            DBType::Timestamp => write!(f, "TIMESTAMP"),
+            DBType::Time => write!(f, "TIME"),
+        }
+    }
+}
+
+impl Display for ReferentialAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReferentialAction::Cascade => write!(f, "CASCADE"),
+            ReferentialAction::Restrict => write!(f, "RESTRICT"),
+            ReferentialAction::SetNull => write!(f, "SET NULL"),
+            ReferentialAction::NoAction => write!(f, "NO ACTION"),
+        }
+    }
+}
+
+impl Display for MatchType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchType::Full => write!(f, "FULL"),
+            MatchType::Partial => write!(f, "PARTIAL"),
+            MatchType::Simple => write!(f, "SIMPLE"),
+        }
+    }
+}
+
+// The SQL-producing implementations below all go through `SqlFormatter`, so that
+// `to_string()` gives text the parser accepts again and parses into an equal AST.
+
+impl Display for DropBehavior {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DropBehavior::Cascade => write!(f, "CASCADE"),
+            DropBehavior::Restrict => write!(f, "RESTRICT"),
+        }
+    }
+}
+
+impl Display for FetchDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchDirection::Next => write!(f, "NEXT"),
+            FetchDirection::Prior => write!(f, "PRIOR"),
+            FetchDirection::First => write!(f, "FIRST"),
+            FetchDirection::Last => write!(f, "LAST"),
+            FetchDirection::Absolute(position) => write!(f, "ABSOLUTE {}", position),
+            FetchDirection::Relative(offset) => write!(f, "RELATIVE {}", offset),
+            FetchDirection::Count(count) => write!(f, "{}", count),
+            FetchDirection::All => write!(f, "ALL"),
+        }
+    }
+}
+
+impl Display for SetOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetOp::Union => write!(f, "UNION"),
+        }
+    }
+}
+
+impl Display for JoinType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "INNER JOIN"),
+            JoinType::Left => write!(f, "LEFT JOIN"),
+            JoinType::Right => write!(f, "RIGHT JOIN"),
+            JoinType::Full => write!(f, "FULL OUTER JOIN"),
+        }
+    }
+}
+
+impl Display for TableRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.alias {
+            Some(alias) => write!(f, "{} AS {}", self.name, alias),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl Display for FrameUnits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameUnits::Rows => write!(f, "ROWS"),
+            FrameUnits::Range => write!(f, "RANGE"),
+        }
+    }
+}
+
+impl Display for FrameExclusion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameExclusion::CurrentRow => write!(f, "CURRENT ROW"),
+            FrameExclusion::Group => write!(f, "GROUP"),
+            FrameExclusion::Ties => write!(f, "TIES"),
+            FrameExclusion::NoOthers => write!(f, "NO OTHERS"),
+        }
+    }
+}
+
+impl Display for Statement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_statement(self))
+    }
+}
+
+impl Display for RpnToken<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpnToken::Operand(expr) => write!(f, "{}", expr),
+            RpnToken::UnaryOperator(operator) => write!(f, "{}", operator),
+            RpnToken::BinaryOperator(operator) => write!(f, "{}", operator),
+            RpnToken::Function { name, arg_count } => write!(f, "{}/{}", name, arg_count),
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_expression(self))
+    }
+}
+
+impl Display for OrderByItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_order_by_item(self))
+    }
+}
+
+impl Display for Window {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Window::Named(name) => write!(f, "{}", name),
+            Window::Spec(spec) => write!(f, "{}", spec),
+        }
+    }
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_window_spec(self))
+    }
+}
+
+impl Display for TableColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_column(self))
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_constraint(self))
+    }
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SqlFormatter::default().format_table_constraint(self))
+    }
 }
\ No newline at end of file
@@ -1,105 +1,308 @@
-use std::fmt::{Debug, Display, Formatter};
-
-#[derive(PartialEq, Clone, Debug)]
-pub enum Token {
-    Keyword(Keyword),
-    Identifier(String),
-    String(String),
-    Number(u64),
-    Invalid(char),
-    RightParentheses,
-    LeftParentheses,
-    GreaterThan,
-    GreaterThanOrEqual,
-    LessThan,
-    LessThanOrEqual,
-    Equal,
-    NotEqual,
-    Multiply,
-    Divide,
-    Minus,
-    Plus,
-    Comma,
-    Semicolon,
-    Eof,
-}
-
-#[derive(PartialEq, Clone, Debug)]
-pub enum Keyword {
-    Select,
-    Create,
-    Table,
-    Where,
-    Order,
-    By,
-    Asc,
-    Desc,
-    From,
-    And,
-    Or,
-    Not,
-    True,
-    False,
-    Primary,
-    Key,
-    Check,
-    Int,
-    Bool,
-    Varchar,
-    Null,
-}
-
-impl Display for Token {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Token::Keyword(keyword) => write!(f, "{}", keyword),
-            Token::Identifier(iden) => write!(f, "{:?}", iden),
-            Token::String(str) => write!(f, "{:?}", str),
-            Token::Number(num) => write!(f, "{:?}", num),
-            Token::RightParentheses => write!(f, "("),
-            Token::LeftParentheses => write!(f, ")"),
-            Token::GreaterThan => write!(f, ">"),
-            Token::GreaterThanOrEqual => write!(f, ">="),
-            Token::LessThan => write!(f, "<"),
-            Token::LessThanOrEqual => write!(f, "<="),
-            Token::Equal => write!(f, "="),
-            Token::NotEqual => write!(f, "!="),
-            Token::Multiply => write!(f, "*"),
-            Token::Divide => write!(f, "/"),
-            Token::Minus => write!(f, "-"),
-            Token::Plus => write!(f, "+"),
-            Token::Comma => write!(f, ","),
-            Token::Semicolon => write!(f, ";"),
-            Token::Eof => write!(f, "Eof"),
-            Token::Invalid(c) => write!(f, "{}", c),
-        }
-    }
-}
-
-impl Display for Keyword {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Keyword::Select => write!(f, "Select"),
-            Keyword::Create => write!(f, "Create"),
-            Keyword::Table => write!(f, "Table"),
-            Keyword::Where => write!(f, "Where"),
-            Keyword::Order => write!(f, "Order"),
-            Keyword::By => write!(f, "By"),
-            Keyword::Asc => write!(f, "Asc"),
-            Keyword::Desc => write!(f, "Desc"),
-            Keyword::From => write!(f, "From"),
-            Keyword::And => write!(f, "And"),
-            Keyword::Or => write!(f, "Or"),
-            Keyword::Not => write!(f, "Not"),
-            Keyword::True => write!(f, "True"),
-            Keyword::False => write!(f, "False"),
-            Keyword::Primary => write!(f, "Primary"),
-            Keyword::Key => write!(f, "Key"),
-            Keyword::Check => write!(f, "Check"),
-            Keyword::Int => write!(f, "Int"),
-            Keyword::Bool => write!(f, "Bool"),
-            Keyword::Varchar => write!(f, "Varchar"),
-            Keyword::Null => write!(f, "Null"),
-        }
-    }
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Token {
+    Keyword(Keyword),
+    Identifier(String),
+    String(String),
+    Number(u64),
+    Float(f64),
+    Invalid(char),
+    RightParentheses,
+    LeftParentheses,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    NullSafeEqual,
+    Equal,
+    NotEqual,
+    Multiply,
+    Divide,
+    Modulo,
+    Concat,
+    Minus,
+    Plus,
+    Comma,
+    Dot,
+    Semicolon,
+    Eof,
+}
+
+/// A value (usually a `Token`) together with the byte range of the input it was read from
+/// `start` is inclusive and `end` is exclusive, so `&input[start..end]` is the original text.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl<T> Spanned<T> {
+    /// The span as a range that can be used to slice the input
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start..self.end
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Keyword {
+    Select,
+    Create,
+    Table,
+    Where,
+    Order,
+    By,
+    Asc,
+    Desc,
+    From,
+    And,
+    Or,
+    Not,
+    True,
+    False,
+    Primary,
+    Key,
+    Check,
+    Int,
+    Bool,
+    Varchar,
+    Null,
+    Update,
+    Set,
+    References,
+    On,
+    Delete,
+    Cascade,
+    Restrict,
+    No,
+    Action,
+    Match,
+    Full,
+    Partial,
+    Simple,
+    Drop,
+    If,
+    Exists,
+    Alter,
+    Add,
+    Column,
+    Date,
+    Timestamp,
+    Time,
+    Like,
+    Insert,
+    Into,
+    Values,
+    Returning,
+    As,
+    Current,
+    Of,
+    Limit,
+    Offset,
+    Group,
+    Window,
+    Over,
+    Partition,
+    Rows,
+    Range,
+    Between,
+    Unbounded,
+    Preceding,
+    Following,
+    Row,
+    Exclude,
+    Ties,
+    Others,
+    Having,
+    Except,
+    Replace,
+    Join,
+    Inner,
+    Boolean,
+    Left,
+    Right,
+    Outer,
+    Smallint,
+    Bigint,
+    Serial,
+    Smallserial,
+    Bigserial,
+    Temporary,
+    Temp,
+    In,
+    Is,
+    Distinct,
+    Case,
+    When,
+    Then,
+    Else,
+    End,
+    Union,
+    All,
+    Unique,
+    Default,
+    Fetch,
+    Move,
+    Float,
+    Double,
+    Decimal,
+    Foreign,
+    Constraint,
+    Char,
+    Text,
+}
+
+impl Keyword {
+    /// Whether the keyword can never be used as a name
+    /// Words that only mean something inside one clause, like `ACTION` in `ON DELETE NO ACTION`, are not
+    /// reserved: where the parser expects a name it reads them as an identifier, so a column can still be
+    /// called `action`. The same goes for the date and time type names, which in an expression only start a
+    /// typed literal when a string follows, and for `TEXT`, which is only ever a column type.
+    pub fn is_reserved(&self) -> bool {
+        !matches!(self, Keyword::No | Keyword::Action | Keyword::Date | Keyword::Timestamp | Keyword::Time | Keyword::Text)
+    }
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Keyword(keyword) => write!(f, "{}", keyword),
+            Token::Identifier(iden) => write!(f, "{:?}", iden),
+            Token::String(str) => write!(f, "{:?}", str),
+            Token::Number(num) => write!(f, "{:?}", num),
+            Token::Float(num) => write!(f, "{:?}", num),
+            Token::RightParentheses => write!(f, "("),
+            Token::LeftParentheses => write!(f, ")"),
+            Token::GreaterThan => write!(f, ">"),
+            Token::GreaterThanOrEqual => write!(f, ">="),
+            Token::LessThan => write!(f, "<"),
+            Token::LessThanOrEqual => write!(f, "<="),
+            Token::NullSafeEqual => write!(f, "<=>"),
+            Token::Equal => write!(f, "="),
+            Token::NotEqual => write!(f, "!="),
+            Token::Multiply => write!(f, "*"),
+            Token::Divide => write!(f, "/"),
+            Token::Modulo => write!(f, "%"),
+            Token::Concat => write!(f, "||"),
+            Token::Minus => write!(f, "-"),
+            Token::Plus => write!(f, "+"),
+            Token::Comma => write!(f, ","),
+            Token::Dot => write!(f, "."),
+            Token::Semicolon => write!(f, ";"),
+            Token::Eof => write!(f, "Eof"),
+            Token::Invalid(c) => write!(f, "{}", c),
+        }
+    }
+}
+
+impl Display for Keyword {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Keyword::Select => write!(f, "Select"),
+            Keyword::Create => write!(f, "Create"),
+            Keyword::Table => write!(f, "Table"),
+            Keyword::Where => write!(f, "Where"),
+            Keyword::Order => write!(f, "Order"),
+            Keyword::By => write!(f, "By"),
+            Keyword::Asc => write!(f, "Asc"),
+            Keyword::Desc => write!(f, "Desc"),
+            Keyword::From => write!(f, "From"),
+            Keyword::And => write!(f, "And"),
+            Keyword::Or => write!(f, "Or"),
+            Keyword::Not => write!(f, "Not"),
+            Keyword::True => write!(f, "True"),
+            Keyword::False => write!(f, "False"),
+            Keyword::Primary => write!(f, "Primary"),
+            Keyword::Key => write!(f, "Key"),
+            Keyword::Check => write!(f, "Check"),
+            Keyword::Int => write!(f, "Int"),
+            Keyword::Bool => write!(f, "Bool"),
+            Keyword::Varchar => write!(f, "Varchar"),
+            Keyword::Null => write!(f, "Null"),
+            Keyword::Update => write!(f, "Update"),
+            Keyword::Set => write!(f, "Set"),
+            Keyword::References => write!(f, "References"),
+            Keyword::On => write!(f, "On"),
+            Keyword::Delete => write!(f, "Delete"),
+            Keyword::Cascade => write!(f, "Cascade"),
+            Keyword::Restrict => write!(f, "Restrict"),
+            Keyword::No => write!(f, "No"),
+            Keyword::Action => write!(f, "Action"),
+            Keyword::Match => write!(f, "Match"),
+            Keyword::Full => write!(f, "Full"),
+            Keyword::Partial => write!(f, "Partial"),
+            Keyword::Simple => write!(f, "Simple"),
+            Keyword::Drop => write!(f, "Drop"),
+            Keyword::If => write!(f, "If"),
+            Keyword::Exists => write!(f, "Exists"),
+            Keyword::Alter => write!(f, "Alter"),
+            Keyword::Add => write!(f, "Add"),
+            Keyword::Column => write!(f, "Column"),
+            Keyword::Date => write!(f, "Date"),
+            Keyword::Timestamp => write!(f, "Timestamp"),
+            Keyword::Time => write!(f, "Time"),
+            Keyword::Like => write!(f, "Like"),
+            Keyword::Insert => write!(f, "Insert"),
+            Keyword::Into => write!(f, "Into"),
+            Keyword::Values => write!(f, "Values"),
+            Keyword::Returning => write!(f, "Returning"),
+            Keyword::As => write!(f, "As"),
+            Keyword::Current => write!(f, "Current"),
+            Keyword::Of => write!(f, "Of"),
+            Keyword::Limit => write!(f, "Limit"),
+            Keyword::Offset => write!(f, "Offset"),
+            Keyword::Group => write!(f, "Group"),
+            Keyword::Window => write!(f, "Window"),
+            Keyword::Over => write!(f, "Over"),
+            Keyword::Partition => write!(f, "Partition"),
+            Keyword::Rows => write!(f, "Rows"),
+            Keyword::Range => write!(f, "Range"),
+            Keyword::Between => write!(f, "Between"),
+            Keyword::Unbounded => write!(f, "Unbounded"),
+            Keyword::Preceding => write!(f, "Preceding"),
+            Keyword::Following => write!(f, "Following"),
+            Keyword::Row => write!(f, "Row"),
+            Keyword::Exclude => write!(f, "Exclude"),
+            Keyword::Ties => write!(f, "Ties"),
+            Keyword::Others => write!(f, "Others"),
+            Keyword::Having => write!(f, "Having"),
+            Keyword::Except => write!(f, "Except"),
+            Keyword::Replace => write!(f, "Replace"),
+            Keyword::Join => write!(f, "Join"),
+            Keyword::Inner => write!(f, "Inner"),
+            Keyword::Boolean => write!(f, "Boolean"),
+            Keyword::Left => write!(f, "Left"),
+            Keyword::Right => write!(f, "Right"),
+            Keyword::Outer => write!(f, "Outer"),
+            Keyword::Smallint => write!(f, "Smallint"),
+            Keyword::Bigint => write!(f, "Bigint"),
+            Keyword::Serial => write!(f, "Serial"),
+            Keyword::Smallserial => write!(f, "Smallserial"),
+            Keyword::Bigserial => write!(f, "Bigserial"),
+            Keyword::Temporary => write!(f, "Temporary"),
+            Keyword::Temp => write!(f, "Temp"),
+            Keyword::In => write!(f, "In"),
+            Keyword::Is => write!(f, "Is"),
+            Keyword::Distinct => write!(f, "Distinct"),
+            Keyword::Case => write!(f, "Case"),
+            Keyword::When => write!(f, "When"),
+            Keyword::Then => write!(f, "Then"),
+            Keyword::Else => write!(f, "Else"),
+            Keyword::End => write!(f, "End"),
+            Keyword::Union => write!(f, "Union"),
+            Keyword::All => write!(f, "All"),
+            Keyword::Unique => write!(f, "Unique"),
+            Keyword::Default => write!(f, "Default"),
+            Keyword::Fetch => write!(f, "Fetch"),
+            Keyword::Move => write!(f, "Move"),
+            Keyword::Float => write!(f, "Float"),
+            Keyword::Double => write!(f, "Double"),
+            Keyword::Decimal => write!(f, "Decimal"),
+            Keyword::Foreign => write!(f, "Foreign"),
+            Keyword::Constraint => write!(f, "Constraint"),
+            Keyword::Char => write!(f, "Char"),
+            Keyword::Text => write!(f, "Text"),
+        }
+    }
 }
\ No newline at end of file
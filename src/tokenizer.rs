@@ -1,250 +1,520 @@
-/// Tokenizer module for SQL statments
-/// This module implments a tokenizer that converts SQL input strings into a stream of tokens.
-/// It handels SQL keywords, identifyers, literals (numbers and strings), and operaters.
-use crate::token::{Token, Keyword};
-use std::iter::Peekable;
-use std::str::Chars;
-
-/// Tokenizer struct that proceses input text character by character
-/// It maintains a peekble iterator over the input characters and tracks the curent position
-pub struct Tokenizer<'a> {
-    input: Peekable<Chars<'a>>,
-    current_position: usize,
-}
-
-impl<'a> Tokenizer<'a> {
-    /// Creates a new Tokenizer instanse with the given input string
-    pub fn new(input: &'a str) -> Self {
-        Tokenizer {
-            input: input.chars().peekable(),
-            current_position: 0,
-        }
-    }
-
-    /// Skips whitespaces characters in the input
-    fn skip_whitespace(&mut self) {
-        while let Some(&c) = self.input.peek() {
-            if !c.is_whitespace() {
-                break;
-            }
-            self.input.next();
-            self.current_position += 1;
-        }
-    }
-
-    /// Reads a number token from the input
-    /// Handels both integer and desimal numbers
-    fn read_number(&mut self) -> Result<Token, String> {
-        let mut number = String::new();
-        let mut has_decimal = false;
-        
-        while let Some(&c) = self.input.peek() {
-            if c == '.' && !has_decimal {
-                has_decimal = true;
-                number.push(c);
-                self.input.next();
-                self.current_position += 1;
-                
-                // Must have at least one digit after decimal point
-                if let Some(&next_c) = self.input.peek() {
-                    if !next_c.is_digit(10) {
-                        return Err(format!("Expected digit after decimal point, got '{}'", next_c));
-                    }
-                } else {
-                    return Err("Unexpected end of input after decimal point".to_string());
-                }
-            } else if c.is_digit(10) {
-                number.push(c);
-                self.input.next();
-                self.current_position += 1;
-            } else {
-                break;
-            }
-        }
-        
-        // If it's a decimal number, convert to equivalent integer
-        if has_decimal {
-            let parts: Vec<&str> = number.split('.').collect();
-            if parts.len() == 2 {
-                let whole = parts[0].parse::<u64>()
-                    .map_err(|_| format!("Invalid integer part in number: {}", parts[0]))?;
-                let decimal = parts[1].parse::<u64>()
-                    .map_err(|_| format!("Invalid decimal part in number: {}", parts[1]))?;
-                let result = whole * 10 + decimal;
-                Ok(Token::Number(result))
-            } else {
-                Err("Invalid decimal number format".to_string())
-            }
-        } else {
-            number.parse::<u64>()
-                .map(Token::Number)
-                .map_err(|_| format!("Invalid number: {}", number))
-        }
-    }
-
-    fn read_identifier_or_keyword(&mut self) -> Result<Token, String> {
-        let mut identifier = String::new();
-        while let Some(&c) = self.input.peek() {
-            if !c.is_alphanumeric() && c != '_' {
-                break;
-            }
-            identifier.push(c);
-            self.input.next();
-            self.current_position += 1;
-        }
-
-        if identifier.is_empty() {
-            return Err("Empty identifier".to_string());
-        }
-
-        Ok(match identifier.to_uppercase().as_str() {
-            "SELECT" => Token::Keyword(Keyword::Select),
-            "CREATE" => Token::Keyword(Keyword::Create),
-            "TABLE" => Token::Keyword(Keyword::Table),
-            "WHERE" => Token::Keyword(Keyword::Where),
-            "ORDER" => Token::Keyword(Keyword::Order),
-            "BY" => Token::Keyword(Keyword::By),
-            "ASC" => Token::Keyword(Keyword::Asc),
-            "DESC" => Token::Keyword(Keyword::Desc),
-            "FROM" => Token::Keyword(Keyword::From),
-            "AND" => Token::Keyword(Keyword::And),
-            "OR" => Token::Keyword(Keyword::Or),
-            "NOT" => Token::Keyword(Keyword::Not),
-            "TRUE" => Token::Keyword(Keyword::True),
-            "FALSE" => Token::Keyword(Keyword::False),
-            "PRIMARY" => Token::Keyword(Keyword::Primary),
-            "KEY" => Token::Keyword(Keyword::Key),
-            "CHECK" => Token::Keyword(Keyword::Check),
-            "INT" => Token::Keyword(Keyword::Int),
-            "BOOL" => Token::Keyword(Keyword::Bool),
-            "VARCHAR" => Token::Keyword(Keyword::Varchar),
-            "NULL" => Token::Keyword(Keyword::Null),
-            _ => Token::Identifier(identifier),
-        })
-    }
-
-    fn read_string(&mut self, quote: char) -> Result<Token, String> {
-        self.input.next(); // Skip the opening quote
-        self.current_position += 1;
-        
-        let mut string = String::new();
-        let mut found_closing_quote = false;
-        
-        while let Some(c) = self.input.next() {
-            self.current_position += 1;
-            if c == quote {
-                found_closing_quote = true;
-                break;
-            }
-            string.push(c);
-        }
-        
-        if !found_closing_quote {
-            return Err(format!("Unterminated string literal starting with {}", quote));
-        }
-        
-        Ok(Token::String(string))
-    }
-}
-
-impl<'a> Iterator for Tokenizer<'a> {
-    type Item = Result<Token, String>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.skip_whitespace();
-
-        match self.input.peek() {
-            None => Some(Ok(Token::Eof)),
-            Some(&c) => {
-                Some(match c {
-                    '0'..='9' => self.read_number(),
-                    'a'..='z' | 'A'..='Z' | '_' => self.read_identifier_or_keyword(),
-                    '\'' | '"' => self.read_string(c),
-                    '(' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::LeftParentheses)
-                    },
-                    ')' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::RightParentheses)
-                    },
-                    ',' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Comma)
-                    },
-                    ';' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Semicolon)
-                    },
-                    '*' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Multiply)
-                    },
-                    '/' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Divide)
-                    },
-                    '+' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Plus)
-                    },
-                    '-' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Minus)
-                    },
-                    '=' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Ok(Token::Equal)
-                    },
-                    '>' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        if let Some(&'=') = self.input.peek() {
-                            self.input.next();
-                            self.current_position += 1;
-                            Ok(Token::GreaterThanOrEqual)
-                        } else {
-                            Ok(Token::GreaterThan)
-                        }
-                    },
-                    '<' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        if let Some(&'=') = self.input.peek() {
-                            self.input.next();
-                            self.current_position += 1;
-                            Ok(Token::LessThanOrEqual)
-                        } else {
-                            Ok(Token::LessThan)
-                        }
-                    },
-                    '!' => {
-                        self.input.next();
-                        self.current_position += 1;
-                        if let Some(&'=') = self.input.peek() {
-                            self.input.next();
-                            self.current_position += 1;
-                            Ok(Token::NotEqual)
-                        } else {
-                            Err(format!("Expected '=' after '!', got unexpected character"))
-                        }
-                    },
-                    c => {
-                        self.input.next();
-                        self.current_position += 1;
-                        Err(format!("Unexpected character: '{}'", c))
-                    }
-                })
-            }
-        }
-    }
-}
+/// Tokenizer module for SQL statments
+/// This module implments a tokenizer that converts SQL input strings into a stream of tokens.
+/// It handels SQL keywords, identifyers, literals (numbers and strings), and operaters.
+use crate::token::{Token, Keyword, Spanned};
+use std::fmt::{self, Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Error produced when the input cannot be split into tokens
+/// `position` is the byte offset where the offending token starts, so callers can point at it
+/// without having to parse it back out of the message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenizeError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl Display for TokenizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+/// Tokenizer struct that proceses input text character by character
+/// It maintains a peekble iterator over the input characters and tracks the curent position
+/// as a byte offset, so every token can be reported together with the span it was read from
+/// As an iterator it yields a single `Token::Eof` at the end of the input and `None` after that.
+pub struct Tokenizer<'a> {
+    source: &'a str,
+    input: Peekable<Chars<'a>>,
+    current_position: usize,
+    token_start: usize,
+    /// Set once `Token::Eof` has been handed out
+    finished: bool,
+    /// A statement terminator used in place of `;`, like the `//` of MySQL's `DELIMITER //`
+    terminator: Option<String>,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Creates a new Tokenizer instanse with the given input string
+    pub fn new(input: &'a str) -> Self {
+        Tokenizer {
+            source: input,
+            input: input.chars().peekable(),
+            current_position: 0,
+            token_start: 0,
+            finished: false,
+            terminator: None,
+        }
+    }
+
+    /// Creates a Tokenizer that reads `terminator` as the end of a statement instead of `;`
+    /// The terminator is handed out as `Token::Semicolon`, so the parser accepts it wherever a `;` would end a
+    /// statement, while a `;` becomes an error. Inside string literals, quoted identifiers and comments the
+    /// terminator is ordinary text. An empty terminator, or `;` itself, leaves the default in place.
+    pub fn with_terminator(input: &'a str, terminator: &str) -> Self {
+        let mut tokenizer = Tokenizer::new(input);
+        if !terminator.is_empty() && terminator != ";" {
+            tokenizer.terminator = Some(terminator.to_string());
+        }
+        tokenizer
+    }
+
+    /// Consumes the tokenizer, yielding every token without its span and leaving out the final `Token::Eof`
+    /// For tools that only need the token values; the sequence ends right after the last real token.
+    pub fn into_tokens(self) -> impl Iterator<Item = Result<Token, TokenizeError>> + 'a {
+        self.filter_map(|result| match result {
+            Ok(Spanned { value: Token::Eof, .. }) => None,
+            Ok(spanned) => Some(Ok(spanned.value)),
+            Err(error) => Some(Err(error)),
+        })
+    }
+
+    /// Splits the whole of `input` into tokens, stopping at the first error
+    /// Like `into_tokens`, the final `Token::Eof` is left out, so `SELECT * FROM t;` gives five tokens.
+    pub fn tokenize(input: &str) -> Result<Vec<Token>, TokenizeError> {
+        Tokenizer::new(input).into_tokens().collect()
+    }
+
+    /// Consumes the next character, keeping the byte position in sync
+    fn bump(&mut self) {
+        if let Some(c) = self.input.next() {
+            self.current_position += c.len_utf8();
+        }
+    }
+
+    /// Skips whitespaces characters in the input
+    fn skip_whitespace(&mut self) {
+        while let Some(&c) = self.input.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.bump();
+        }
+    }
+
+    /// Skips whitespace and comments up to the start of the next token, which is recorded in `token_start`
+    /// Comments are skipped in a loop rather than by reading a token again after each one, so any number of
+    /// consecutive comments is fine. An unterminated block comment is an error at the position it starts.
+    fn skip_whitespace_and_comments(&mut self) -> Result<(), String> {
+        loop {
+            self.skip_whitespace();
+            self.token_start = self.current_position;
+            let rest = &self.source[self.current_position..];
+            if rest.starts_with("--") {
+                self.skip_line_comment();
+            } else if rest.starts_with("/*") {
+                self.bump(); // Skip the '/'
+                self.skip_block_comment()?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Skips a `--` comment up to and including the end of the line (or the end of input)
+    fn skip_line_comment(&mut self) {
+        for c in self.input.by_ref() {
+            self.current_position += c.len_utf8();
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    /// Skips a `/* ... */` comment, which may span several lines
+    /// Expects the opening '/' to be consumed already and the '*' to be next.
+    fn skip_block_comment(&mut self) -> Result<(), String> {
+        self.bump(); // Skip the opening '*'
+
+        let mut previous = None;
+        for c in self.input.by_ref() {
+            self.current_position += c.len_utf8();
+            if previous == Some('*') && c == '/' {
+                return Ok(());
+            }
+            previous = Some(c);
+        }
+        Err("Unterminated block comment".to_string())
+    }
+
+    /// Reads a number token from the input
+    /// Handels both integer and desimal numbers, desimals become `Token::Float`
+    fn read_number(&mut self) -> Result<Token, String> {
+        let mut number = String::new();
+        let mut has_decimal = false;
+        
+        while let Some(&c) = self.input.peek() {
+            if c == '.' && !has_decimal {
+                has_decimal = true;
+                number.push(c);
+                self.bump();
+                
+                // Must have at least one digit after decimal point
+                if let Some(&next_c) = self.input.peek() {
+                    if !next_c.is_ascii_digit() {
+                        return Err(format!("Expected digit after decimal point, got '{}'", next_c));
+                    }
+                } else {
+                    return Err("Unexpected end of input after decimal point".to_string());
+                }
+            } else if c.is_ascii_digit() {
+                number.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        // Letters glued to a number, like a pasted `10K` or `5MB`, are not a number followed by an identifier
+        if matches!(self.input.peek(), Some(&c) if c.is_alphabetic() || c == '_') {
+            while let Some(&c) = self.input.peek() {
+                if !c.is_alphanumeric() && c != '_' {
+                    break;
+                }
+                number.push(c);
+                self.bump();
+            }
+            return Err(format!("invalid numeric literal '{}'", number));
+        }
+        
+        // A decimal point makes it a floating-point number
+        if has_decimal {
+            number.parse::<f64>()
+                .map(Token::Float)
+                .map_err(|_| format!("Invalid decimal number: {}", number))
+        } else {
+            number.parse::<u64>()
+                .map(Token::Number)
+                .map_err(|_| format!("Invalid number: {}", number))
+        }
+    }
+
+    fn read_identifier_or_keyword(&mut self) -> Result<Token, String> {
+        let mut identifier = String::new();
+        while let Some(&c) = self.input.peek() {
+            if !c.is_alphanumeric() && c != '_' {
+                break;
+            }
+            identifier.push(c);
+            self.bump();
+        }
+
+        if identifier.is_empty() {
+            return Err("Empty identifier".to_string());
+        }
+
+        Ok(match keyword(&identifier) {
+            Some(keyword) => Token::Keyword(keyword),
+            None => Token::Identifier(identifier),
+        })
+    }
+
+    /// Reads a string literal enclosed in single quotes
+    /// The quote character itself is written by doubling it, as in `'O''Brien'`.
+    fn read_string(&mut self) -> Result<Token, String> {
+        self.read_quoted('\'', "string literal").map(Token::String)
+    }
+
+    /// Reads an identifier enclosed in double quotes or backticks, like `"order"` or `` `first name` ``
+    /// The name is kept exactly as written, so it is never read as a keyword and may contain spaces.
+    fn read_quoted_identifier(&mut self, quote: char) -> Result<Token, String> {
+        let name = self.read_quoted(quote, "quoted identifier")?;
+        if name.is_empty() {
+            return Err("Empty quoted identifier".to_string());
+        }
+        Ok(Token::Identifier(name))
+    }
+
+    /// Reads the text enclosed in `quote`, where a doubled quote stands for the quote character itself
+    fn read_quoted(&mut self, quote: char, what: &str) -> Result<String, String> {
+        self.bump(); // Skip the opening quote
+        
+        let mut string = String::new();
+        let mut found_closing_quote = false;
+        
+        while let Some(c) = self.input.next() {
+            self.current_position += c.len_utf8();
+            if c == quote {
+                // A doubled quote is an escaped quote character, not the end of the string
+                if self.input.peek() == Some(&quote) {
+                    self.bump();
+                    string.push(quote);
+                    continue;
+                }
+                found_closing_quote = true;
+                break;
+            }
+            string.push(c);
+        }
+        
+        if !found_closing_quote {
+            return Err(format!("Unterminated {} starting with {}", what, quote));
+        }
+        
+        Ok(string)
+    }
+}
+
+/// The keyword spelled by `word`, in any letter case, or `None` when it is an ordinary identifier
+pub(crate) fn keyword(word: &str) -> Option<Keyword> {
+    Some(match word.to_uppercase().as_str() {
+        "SELECT" => Keyword::Select,
+        "CREATE" => Keyword::Create,
+        "TABLE" => Keyword::Table,
+        "WHERE" => Keyword::Where,
+        "ORDER" => Keyword::Order,
+        "BY" => Keyword::By,
+        "ASC" => Keyword::Asc,
+        "DESC" => Keyword::Desc,
+        "FROM" => Keyword::From,
+        "AND" => Keyword::And,
+        "OR" => Keyword::Or,
+        "NOT" => Keyword::Not,
+        "TRUE" => Keyword::True,
+        "FALSE" => Keyword::False,
+        "PRIMARY" => Keyword::Primary,
+        "KEY" => Keyword::Key,
+        "CHECK" => Keyword::Check,
+        "INT" => Keyword::Int,
+        "BOOL" => Keyword::Bool,
+        "VARCHAR" => Keyword::Varchar,
+        "NULL" => Keyword::Null,
+        "UPDATE" => Keyword::Update,
+        "SET" => Keyword::Set,
+        "REFERENCES" => Keyword::References,
+        "ON" => Keyword::On,
+        "DELETE" => Keyword::Delete,
+        "CASCADE" => Keyword::Cascade,
+        "RESTRICT" => Keyword::Restrict,
+        "NO" => Keyword::No,
+        "ACTION" => Keyword::Action,
+        "MATCH" => Keyword::Match,
+        "FULL" => Keyword::Full,
+        "PARTIAL" => Keyword::Partial,
+        "SIMPLE" => Keyword::Simple,
+        "DROP" => Keyword::Drop,
+        "IF" => Keyword::If,
+        "EXISTS" => Keyword::Exists,
+        "ALTER" => Keyword::Alter,
+        "ADD" => Keyword::Add,
+        "COLUMN" => Keyword::Column,
+        "DATE" => Keyword::Date,
+        "TIMESTAMP" => Keyword::Timestamp,
+        "TIME" => Keyword::Time,
+        "LIKE" => Keyword::Like,
+        "INSERT" => Keyword::Insert,
+        "INTO" => Keyword::Into,
+        "VALUES" => Keyword::Values,
+        "RETURNING" => Keyword::Returning,
+        "AS" => Keyword::As,
+        "CURRENT" => Keyword::Current,
+        "OF" => Keyword::Of,
+        "LIMIT" => Keyword::Limit,
+        "OFFSET" => Keyword::Offset,
+        "GROUP" => Keyword::Group,
+        "WINDOW" => Keyword::Window,
+        "OVER" => Keyword::Over,
+        "PARTITION" => Keyword::Partition,
+        "ROWS" => Keyword::Rows,
+        "RANGE" => Keyword::Range,
+        "BETWEEN" => Keyword::Between,
+        "UNBOUNDED" => Keyword::Unbounded,
+        "PRECEDING" => Keyword::Preceding,
+        "FOLLOWING" => Keyword::Following,
+        "ROW" => Keyword::Row,
+        "EXCLUDE" => Keyword::Exclude,
+        "TIES" => Keyword::Ties,
+        "OTHERS" => Keyword::Others,
+        "HAVING" => Keyword::Having,
+        "EXCEPT" => Keyword::Except,
+        "REPLACE" => Keyword::Replace,
+        "JOIN" => Keyword::Join,
+        "INNER" => Keyword::Inner,
+        "BOOLEAN" => Keyword::Boolean,
+        "LEFT" => Keyword::Left,
+        "RIGHT" => Keyword::Right,
+        "OUTER" => Keyword::Outer,
+        "SMALLINT" => Keyword::Smallint,
+        "BIGINT" => Keyword::Bigint,
+        "SERIAL" => Keyword::Serial,
+        "SMALLSERIAL" => Keyword::Smallserial,
+        "BIGSERIAL" => Keyword::Bigserial,
+        "TEMPORARY" => Keyword::Temporary,
+        "TEMP" => Keyword::Temp,
+        "IN" => Keyword::In,
+        "IS" => Keyword::Is,
+        "DISTINCT" => Keyword::Distinct,
+        "CASE" => Keyword::Case,
+        "WHEN" => Keyword::When,
+        "THEN" => Keyword::Then,
+        "ELSE" => Keyword::Else,
+        "END" => Keyword::End,
+        "UNION" => Keyword::Union,
+        "ALL" => Keyword::All,
+        "UNIQUE" => Keyword::Unique,
+        "DEFAULT" => Keyword::Default,
+        "FETCH" => Keyword::Fetch,
+        "MOVE" => Keyword::Move,
+        "FLOAT" => Keyword::Float,
+        "DOUBLE" => Keyword::Double,
+        "DECIMAL" => Keyword::Decimal,
+        "FOREIGN" => Keyword::Foreign,
+        "CONSTRAINT" => Keyword::Constraint,
+        "CHAR" => Keyword::Char,
+        "TEXT" => Keyword::Text,
+        _ => return None,
+    })
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Spanned<Token>, TokenizeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let token = self.read_token()?;
+        if let Ok(Token::Eof) = token {
+            self.finished = true;
+        }
+        Some(match token {
+            Ok(token) => Ok(Spanned {
+                value: token,
+                start: self.token_start,
+                end: self.current_position,
+            }),
+            Err(message) => Err(TokenizeError {
+                message,
+                position: self.token_start,
+            }),
+        })
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Reads the next token, recording where it starts in `token_start`
+    fn read_token(&mut self) -> Option<Result<Token, String>> {
+        if let Err(e) = self.skip_whitespace_and_comments() {
+            return Some(Err(e));
+        }
+
+        if let Some(terminator) = &self.terminator {
+            if self.source[self.current_position..].starts_with(terminator.as_str()) {
+                for _ in 0..terminator.chars().count() {
+                    self.bump();
+                }
+                return Some(Ok(Token::Semicolon));
+            }
+        }
+
+        match self.input.peek() {
+            None => Some(Ok(Token::Eof)),
+            Some(&c) => {
+                Some(match c {
+                    '0'..='9' => self.read_number(),
+                    'a'..='z' | 'A'..='Z' | '_' => self.read_identifier_or_keyword(),
+                    '\'' => self.read_string(),
+                    '"' | '`' => self.read_quoted_identifier(c),
+                    '(' => {
+                        self.bump();
+                        Ok(Token::LeftParentheses)
+                    },
+                    ')' => {
+                        self.bump();
+                        Ok(Token::RightParentheses)
+                    },
+                    ',' => {
+                        self.bump();
+                        Ok(Token::Comma)
+                    },
+                    '.' => {
+                        self.bump();
+                        Ok(Token::Dot)
+                    },
+                    ';' => {
+                        self.bump();
+                        match &self.terminator {
+                            Some(terminator) => Err(format!("Unexpected ';', statements end with {}", terminator)),
+                            None => Ok(Token::Semicolon),
+                        }
+                    },
+                    '*' => {
+                        self.bump();
+                        Ok(Token::Multiply)
+                    },
+                    '/' => {
+                        self.bump();
+                        Ok(Token::Divide)
+                    },
+                    '%' => {
+                        self.bump();
+                        Ok(Token::Modulo)
+                    },
+                    '+' => {
+                        self.bump();
+                        Ok(Token::Plus)
+                    },
+                    '-' => {
+                        self.bump();
+                        Ok(Token::Minus)
+                    },
+                    '=' => {
+                        self.bump();
+                        Ok(Token::Equal)
+                    },
+                    '>' => {
+                        self.bump();
+                        if let Some(&'=') = self.input.peek() {
+                            self.bump();
+                            Ok(Token::GreaterThanOrEqual)
+                        } else {
+                            Ok(Token::GreaterThan)
+                        }
+                    },
+                    '<' => {
+                        self.bump();
+                        if let Some(&'=') = self.input.peek() {
+                            self.bump();
+                            if let Some(&'>') = self.input.peek() {
+                                self.bump();
+                                Ok(Token::NullSafeEqual)
+                            } else {
+                                Ok(Token::LessThanOrEqual)
+                            }
+                        } else if let Some(&'>') = self.input.peek() {
+                            // `<>` is the standard spelling of `!=`
+                            self.bump();
+                            Ok(Token::NotEqual)
+                        } else {
+                            Ok(Token::LessThan)
+                        }
+                    },
+                    '|' => {
+                        self.bump();
+                        if let Some(&'|') = self.input.peek() {
+                            self.bump();
+                            Ok(Token::Concat)
+                        } else {
+                            Err("Expected '|' after '|', got unexpected character".to_string())
+                        }
+                    },
+                    '!' => {
+                        self.bump();
+                        if let Some(&'=') = self.input.peek() {
+                            self.bump();
+                            Ok(Token::NotEqual)
+                        } else {
+                            Err("Expected '=' after '!', got unexpected character".to_string())
+                        }
+                    },
+                    c => {
+                        self.bump();
+                        Err(format!("Unexpected character: '{}'", c))
+                    }
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Shared by several test crates, each of which uses only some of the helpers
+#![allow(dead_code)]
+
+use sql_parser::dialect::Dialect;
+use sql_parser::error::ParseError;
+use sql_parser::parser::Parser;
+use sql_parser::statement::{Expression, Statement};
+use sql_parser::tokenizer::Tokenizer;
+
+pub fn statement(sql: &str) -> Statement {
+    sql_parser::parse(sql).unwrap_or_else(|error| panic!("failed to parse {:?}: {}", sql, error))
+}
+
+pub fn statement_error(sql: &str) -> ParseError {
+    match sql_parser::parse(sql) {
+        Ok(statement) => panic!("expected {:?} to fail, got {:?}", sql, statement),
+        Err(error) => error,
+    }
+}
+
+pub fn dialect_statement(sql: &str, dialect: Dialect) -> Statement {
+    sql_parser::parse_with_dialect(sql, dialect).unwrap_or_else(|error| panic!("failed to parse {:?}: {}", sql, error))
+}
+
+pub fn expression(sql: &str) -> Expression {
+    try_expression(sql).unwrap_or_else(|error| panic!("failed to parse {:?}: {}", sql, error))
+}
+
+pub fn try_expression(sql: &str) -> Result<Expression, ParseError> {
+    Parser::new(Tokenizer::new(sql)).parse_expression()
+}
+
+pub fn expression_error(sql: &str) -> ParseError {
+    match try_expression(sql) {
+        Ok(expr) => panic!("expected {:?} to fail, got {:?}", sql, expr),
+        Err(error) => error,
+    }
+}
+
+pub fn ident(name: &str) -> Expression {
+    Expression::Identifier(name.to_string())
+}
+
+/// Renders `sql` and parses the output again, checking that it comes back as the same statement
+/// Returns the rendered SQL so callers can also check its exact text.
+pub fn round_trip(sql: &str) -> String {
+    let parsed = statement(sql);
+    let rendered = parsed.to_string();
+    assert_eq!(statement(&rendered), parsed, "{:?} rendered as {:?}", sql, rendered);
+    rendered
+}
+
+/// Like `round_trip`, for a single expression
+pub fn expression_round_trip(sql: &str) -> String {
+    let parsed = expression(sql);
+    let rendered = parsed.to_string();
+    assert_eq!(expression(&rendered), parsed, "{:?} rendered as {:?}", sql, rendered);
+    rendered
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{expression, statement};
+
+#[test]
+fn binary_operations_become_nodes_with_operand_edges() {
+    let dot = expression("a + b * c").to_dot();
+    assert!(dot.starts_with("digraph Expression {\n"));
+    for line in [
+        "n0 [label=\"+\"];",
+        "n1 [label=\"a\"];",
+        "n0 -> n1;",
+        "n2 [label=\"*\"];",
+        "n0 -> n2;",
+        "n3 [label=\"b\"];",
+        "n4 [label=\"c\"];",
+        "n2 -> n3;",
+        "n2 -> n4;",
+    ] {
+        assert!(dot.contains(line), "{:?} missing from\n{}", line, dot);
+    }
+}
+
+#[test]
+fn statement_clauses_become_nodes() {
+    let dot = statement("SELECT a FROM t WHERE b = 1;").to_dot();
+    assert!(dot.starts_with("digraph Statement {\n"));
+    for line in ["n0 [label=\"SELECT\"];", "[label=\"FROM t\"];", "[label=\"WHERE\"];", "[label=\"=\"];"] {
+        assert!(dot.contains(line), "{:?} missing from\n{}", line, dot);
+    }
+}
+
+#[test]
+fn labels_are_escaped() {
+    let dot = expression("'say \"hi\"'").to_dot();
+    assert!(dot.contains(r#"\"hi\""#), "{}", dot);
+}
@@ -0,0 +1,349 @@
+mod common;
+
+use common::{expression, expression_error, expression_round_trip, ident};
+use sql_parser::dialect::Dialect;
+use sql_parser::error::ParseError;
+use sql_parser::parser::Parser;
+use sql_parser::statement::{BinaryOperator, DBType, Expression, UnaryOperator};
+use sql_parser::tokenizer::Tokenizer;
+
+#[test]
+fn qualified_column() {
+    assert_eq!(
+        expression("a.b"),
+        Expression::QualifiedIdentifier { qualifier: "a".to_string(), name: "b".to_string() }
+    );
+}
+
+#[test]
+fn qualified_wildcard() {
+    assert_eq!(
+        expression("a.*"),
+        Expression::QualifiedIdentifier { qualifier: "a".to_string(), name: "*".to_string() }
+    );
+}
+
+#[test]
+fn float_literal() {
+    assert_eq!(
+        expression("price > 9.99"),
+        Expression::BinaryOperation {
+            left_operand: Box::new(Expression::Identifier("price".to_string())),
+            operator: BinaryOperator::GreaterThan,
+            right_operand: Box::new(Expression::Float(9.99)),
+        }
+    );
+}
+
+#[test]
+fn exists_and_not_exists() {
+    assert!(matches!(expression("EXISTS (SELECT 1 FROM t)"), Expression::Exists { negated: false, .. }));
+    assert!(matches!(expression("NOT EXISTS (SELECT 1 FROM t WHERE a = 1)"), Expression::Exists { negated: true, .. }));
+    assert!(matches!(
+        expression("NOT (EXISTS (SELECT 1 FROM t))"),
+        Expression::UnaryOperation { operator: UnaryOperator::Not, .. }
+    ));
+}
+
+#[test]
+fn in_subquery_and_in_list() {
+    assert!(matches!(expression("a IN (SELECT b FROM t)"), Expression::InSubquery { negated: false, .. }));
+    assert!(matches!(expression("a NOT IN (SELECT b FROM t)"), Expression::InSubquery { negated: true, .. }));
+    assert!(matches!(expression("a IN ((SELECT b FROM t))"), Expression::InList { .. }));
+}
+
+#[test]
+fn scalar_subquery() {
+    assert!(matches!(expression("(SELECT MAX(b) FROM t) + 1"), Expression::BinaryOperation { .. }));
+    assert!(matches!(expression("(SELECT b FROM t ORDER BY b)"), Expression::Subquery(_)));
+}
+
+#[test]
+fn typed_literals() {
+    assert_eq!(
+        expression("DATE '2020-01-01'"),
+        Expression::TypedLiteral { ty: DBType::Date, value: "2020-01-01".to_string() }
+    );
+    assert_eq!(
+        expression("TIMESTAMP '2020-01-01 00:00:00'"),
+        Expression::TypedLiteral { ty: DBType::Timestamp, value: "2020-01-01 00:00:00".to_string() }
+    );
+    assert_eq!(expression("TIME '12:30'"), Expression::TypedLiteral { ty: DBType::Time, value: "12:30".to_string() });
+}
+
+#[test]
+fn type_keyword_without_a_string_is_a_name() {
+    assert_eq!(
+        expression("DATE + 1"),
+        Expression::BinaryOperation { left_operand: Box::new(ident("date")), operator: BinaryOperator::Plus, right_operand: Box::new(Expression::Number(1)) }
+    );
+    assert_eq!(expression("timestamp"), ident("timestamp"));
+    assert!(matches!(expression("DATE '2024-01-01'"), Expression::TypedLiteral { ty: DBType::Date, .. }));
+}
+
+#[test]
+fn function_call_arity() {
+    assert_eq!(expression("NOW()").arg_count(), 0);
+    assert_eq!(expression("CONCAT(a, b, c)").arg_count(), 3);
+    assert_eq!(expression("CONCAT_WS(',', a, b, c, d)").arg_count(), 5);
+    assert_eq!(expression("a + b").arg_count(), 0);
+}
+
+#[test]
+fn empty_parentheses() {
+    assert_eq!(expression("COUNT()"), Expression::FunctionCall { name: "COUNT".to_string(), distinct: false, args: vec![] });
+    for sql in ["()", "1 + ()"] {
+        assert_eq!(
+            expression_error(sql),
+            ParseError::Invalid("empty parentheses are not a valid expression".to_string()),
+            "{}",
+            sql
+        );
+    }
+}
+
+fn string(value: &str) -> Expression {
+    Expression::String(value.to_string())
+}
+
+#[test]
+fn in_list() {
+    assert_eq!(
+        expression("status IN ('active', 'pending', 'closed')"),
+        Expression::InList {
+            expr: Box::new(ident("status")),
+            list: vec![string("active"), string("pending"), string("closed")],
+            negated: false,
+        }
+    );
+    assert_eq!(
+        expression("status NOT IN ('closed')"),
+        Expression::InList { expr: Box::new(ident("status")), list: vec![string("closed")], negated: true }
+    );
+}
+
+#[test]
+fn between_binds_tighter_than_and() {
+    assert_eq!(
+        expression("a BETWEEN 1 AND 2 AND b = 3"),
+        Expression::BinaryOperation {
+            left_operand: Box::new(Expression::Between {
+                expr: Box::new(ident("a")),
+                low: Box::new(Expression::Number(1)),
+                high: Box::new(Expression::Number(2)),
+                negated: false,
+            }),
+            operator: BinaryOperator::And,
+            right_operand: Box::new(Expression::BinaryOperation {
+                left_operand: Box::new(ident("b")),
+                operator: BinaryOperator::Equal,
+                right_operand: Box::new(Expression::Number(3)),
+            }),
+        }
+    );
+    assert!(matches!(expression("age NOT BETWEEN 18 AND 65"), Expression::Between { negated: true, .. }));
+}
+
+#[test]
+fn like_and_not_like() {
+    assert_eq!(
+        expression("name LIKE 'A%'"),
+        Expression::Like { expr: Box::new(ident("name")), pattern: Box::new(string("A%")), negated: false }
+    );
+    assert!(matches!(expression("name NOT LIKE 'A%'"), Expression::Like { negated: true, .. }));
+}
+
+#[test]
+fn is_null_and_is_not_null() {
+    assert_eq!(expression("email IS NULL"), Expression::IsNull { expr: Box::new(ident("email")), negated: false });
+    assert_eq!(expression("email IS NOT NULL"), Expression::IsNull { expr: Box::new(ident("email")), negated: true });
+}
+
+#[test]
+fn is_distinct_from() {
+    assert_eq!(
+        expression("a IS DISTINCT FROM b"),
+        Expression::IsDistinctFrom { left: Box::new(ident("a")), right: Box::new(ident("b")), negated: false }
+    );
+    assert_eq!(
+        expression("a IS NOT DISTINCT FROM b + 1"),
+        Expression::IsDistinctFrom {
+            left: Box::new(ident("a")),
+            right: Box::new(Expression::BinaryOperation {
+                left_operand: Box::new(ident("b")),
+                operator: BinaryOperator::Plus,
+                right_operand: Box::new(Expression::Number(1)),
+            }),
+            negated: true,
+        }
+    );
+    assert!(matches!(expression("a IS NULL"), Expression::IsNull { negated: false, .. }));
+    expression_error("a IS DISTINCT b");
+}
+
+#[test]
+fn mysql_null_safe_equal() {
+    let mysql = |sql: &str| Parser::with_dialect(Tokenizer::new(sql), Dialect::MySql).parse_expression();
+    assert_eq!(
+        mysql("a <=> b").unwrap(),
+        Expression::BinaryOperation {
+            left_operand: Box::new(ident("a")),
+            operator: BinaryOperator::NullSafeEqual,
+            right_operand: Box::new(ident("b")),
+        }
+    );
+    assert!(matches!(
+        mysql("a <=> b AND c <= d").unwrap(),
+        Expression::BinaryOperation { operator: BinaryOperator::And, .. }
+    ));
+    expression_error("a <=> b");
+}
+
+fn call(name: &str, args: Vec<Expression>) -> Expression {
+    Expression::FunctionCall { name: name.to_string(), distinct: false, args }
+}
+
+#[test]
+fn function_calls() {
+    assert_eq!(expression("NOW()"), call("NOW", vec![]));
+    assert_eq!(expression("COUNT(*)"), call("COUNT", vec![ident("*")]));
+    assert_eq!(expression("UPPER(name)"), call("UPPER", vec![ident("name")]));
+    assert_eq!(
+        expression("COALESCE(a, 'x', 1 + 2)"),
+        call(
+            "COALESCE",
+            vec![
+                ident("a"),
+                Expression::String("x".to_string()),
+                Expression::BinaryOperation {
+                    left_operand: Box::new(Expression::Number(1)),
+                    operator: BinaryOperator::Plus,
+                    right_operand: Box::new(Expression::Number(2)),
+                },
+            ]
+        )
+    );
+    expression_error("SUM(a, )");
+    expression_error("SUM(*, a)");
+}
+
+#[test]
+fn qualified_wildcard_arguments() {
+    let wildcard = Expression::QualifiedIdentifier { qualifier: "t".to_string(), name: "*".to_string() };
+    assert_eq!(expression("COUNT(t.*)"), call("COUNT", vec![wildcard.clone()]));
+    assert_eq!(expression("SUM(t.*)"), call("SUM", vec![wildcard]));
+    assert_eq!(expression("COUNT(*)"), call("COUNT", vec![ident("*")]));
+}
+
+#[test]
+fn count_distinct() {
+    assert_eq!(
+        expression("COUNT(DISTINCT x)"),
+        Expression::FunctionCall { name: "COUNT".to_string(), distinct: true, args: vec![ident("x")] }
+    );
+    assert_ne!(expression("COUNT(DISTINCT x)"), expression("COUNT(x)"));
+    assert_eq!(expression_round_trip("COUNT(DISTINCT x)"), "COUNT(DISTINCT x)");
+}
+
+#[test]
+fn modulo_binds_like_multiplication() {
+    assert_eq!(
+        expression("1 + 5 % 2"),
+        Expression::BinaryOperation {
+            left_operand: Box::new(Expression::Number(1)),
+            operator: BinaryOperator::Plus,
+            right_operand: Box::new(Expression::BinaryOperation {
+                left_operand: Box::new(Expression::Number(5)),
+                operator: BinaryOperator::Modulo,
+                right_operand: Box::new(Expression::Number(2)),
+            }),
+        }
+    );
+    assert_eq!(expression_round_trip("(1 + 5) % 2 * 3"), "(1 + 5) % 2 * 3");
+}
+
+#[test]
+fn concatenation_is_left_associative() {
+    let concat = |left: Expression, right: Expression| Expression::BinaryOperation {
+        left_operand: Box::new(left),
+        operator: BinaryOperator::Concat,
+        right_operand: Box::new(right),
+    };
+    assert_eq!(
+        expression("first_name || ' ' || last_name || '!'"),
+        concat(
+            concat(concat(ident("first_name"), Expression::String(" ".to_string())), ident("last_name")),
+            Expression::String("!".to_string())
+        )
+    );
+    assert!(Tokenizer::tokenize("a | b").is_err());
+}
+
+#[test]
+fn multi_word_keywords_after_is() {
+    assert!(matches!(expression("a IS NOT NULL AND b"), Expression::BinaryOperation { operator: BinaryOperator::And, .. }));
+    assert!(matches!(expression("a IS NOT DISTINCT FROM b"), Expression::IsDistinctFrom { negated: true, .. }));
+    expression_error("a IS NOT");
+    expression_error("a IS NOT 1");
+    expression_error("a IS DISTINCT");
+}
+
+#[test]
+fn percent_is_modulo_only_between_operands() {
+    assert!(matches!(expression("a % b"), Expression::BinaryOperation { operator: BinaryOperator::Modulo, .. }));
+    assert_eq!(
+        expression_error("%1"),
+        ParseError::Invalid("% needs a left operand, since %-style parameter placeholders are not supported".to_string())
+    );
+    assert!(matches!(expression_error("a = %name"), ParseError::Invalid(_)));
+}
+
+fn rpn(sql: &str) -> Vec<String> {
+    expression(sql).to_rpn().iter().map(|token| token.to_string()).collect()
+}
+
+#[test]
+fn reverse_polish_notation() {
+    assert_eq!(rpn("a + b * c"), ["a", "b", "c", "*", "+"]);
+    assert_eq!(rpn("(a + b) * c"), ["a", "b", "+", "c", "*"]);
+    assert_eq!(rpn("-a"), ["a", "-"]);
+    assert_eq!(rpn("f(a, 1)"), ["a", "1", "f/2"]);
+}
+
+#[test]
+fn exists_requires_a_subquery() {
+    assert!(matches!(expression("EXISTS (SELECT 1 FROM t)"), Expression::Exists { .. }));
+    for sql in ["EXISTS (1)", "EXISTS (1 + 2)", "NOT EXISTS (a)"] {
+        assert_eq!(expression_error(sql), ParseError::Invalid("EXISTS requires a subquery".to_string()), "{}", sql);
+    }
+}
+
+#[test]
+fn both_not_equal_spellings() {
+    assert_eq!(expression("a <> b"), expression("a != b"));
+    assert!(matches!(expression("a <> b"), Expression::BinaryOperation { operator: BinaryOperator::NotEqual, .. }));
+    assert!(matches!(expression("a <= b"), Expression::BinaryOperation { operator: BinaryOperator::LessThanOrEqual, .. }));
+}
+
+#[test]
+fn type_hints() {
+    for (sql, hint) in [
+        ("1", Some(DBType::Int)),
+        ("1.5", Some(DBType::Float)),
+        ("'a'", Some(DBType::Text)),
+        ("TRUE", Some(DBType::Bool)),
+        ("DATE '2020-01-01'", Some(DBType::Date)),
+        ("a > b", Some(DBType::Bool)),
+        ("NOT a", Some(DBType::Bool)),
+        ("a IS NULL", Some(DBType::Bool)),
+        ("a || b", Some(DBType::Text)),
+        ("-1", Some(DBType::Int)),
+        ("col", None),
+        ("t.col", None),
+        ("a + 1", None),
+        ("UPPER(a)", None),
+        ("NULL", None),
+    ] {
+        assert_eq!(expression(sql).type_hint(), hint, "{}", sql);
+    }
+}
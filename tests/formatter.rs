@@ -0,0 +1,150 @@
+mod common;
+
+use common::{expression, expression_round_trip, round_trip, statement};
+use sql_parser::dialect::Dialect;
+use sql_parser::formatter::{FormatOptions, SqlFormatter};
+use sql_parser::parser::Parser;
+use sql_parser::statement::{Expression, Statement};
+use sql_parser::tokenizer::Tokenizer;
+
+#[test]
+fn float_literals_round_trip_in_decimal_notation() {
+    assert_eq!(expression_round_trip("0.00001"), "0.00001");
+    assert_eq!(expression_round_trip("2.0"), "2.0");
+    assert_eq!(expression_round_trip("9.99"), "9.99");
+    let large = expression_round_trip("12345678901234567.5");
+    assert!(!large.contains('e'), "{}", large);
+    assert_eq!(expression(&large), Expression::Float(12345678901234567.5));
+}
+
+#[test]
+fn float_in_check_constraint_round_trips() {
+    assert_eq!(
+        round_trip("CREATE TABLE t (price FLOAT CHECK (price > 0.5));"),
+        "CREATE TABLE t (price FLOAT CHECK (price > 0.5));"
+    );
+}
+
+#[test]
+fn exists_round_trips() {
+    assert_eq!(
+        round_trip("SELECT a FROM t WHERE EXISTS (SELECT 1 FROM u WHERE u.id = t.id);"),
+        "SELECT a FROM t WHERE EXISTS (SELECT 1 FROM u WHERE u.id = t.id);"
+    );
+    assert_eq!(round_trip("SELECT a FROM t WHERE NOT EXISTS (SELECT 1 FROM u);"), "SELECT a FROM t WHERE NOT EXISTS (SELECT 1 FROM u);");
+}
+
+#[test]
+fn not_over_exists_keeps_its_parentheses() {
+    assert_eq!(
+        round_trip("SELECT a FROM t WHERE NOT (EXISTS (SELECT 1 FROM u));"),
+        "SELECT a FROM t WHERE NOT (EXISTS (SELECT 1 FROM u));"
+    );
+    round_trip("SELECT a FROM t WHERE NOT NOT EXISTS (SELECT 1 FROM u);");
+}
+
+#[test]
+fn subqueries_round_trip() {
+    assert_eq!(round_trip("SELECT a FROM t WHERE a IN (SELECT b FROM u);"), "SELECT a FROM t WHERE a IN (SELECT b FROM u);");
+    assert_eq!(round_trip("SELECT a FROM t WHERE a NOT IN (SELECT b FROM u);"), "SELECT a FROM t WHERE a NOT IN (SELECT b FROM u);");
+    assert_eq!(round_trip("SELECT (SELECT MAX(b) FROM u) FROM t;"), "SELECT (SELECT MAX(b) FROM u) FROM t;");
+}
+
+#[test]
+fn group_digits_option() {
+    let grouped = SqlFormatter::new(FormatOptions { group_digits: true, ..Default::default() });
+    assert_eq!(grouped.format_expression(&expression("1000000 + 999")), "1_000_000 + 999");
+    assert_eq!(grouped.format_expression(&expression("1234567890")), "1_234_567_890");
+    assert_eq!(SqlFormatter::default().format_expression(&expression("1000000")), "1000000");
+}
+
+#[test]
+fn typed_literal_round_trips() {
+    assert_eq!(
+        round_trip("SELECT a FROM t WHERE d > DATE '2020-01-01';"),
+        "SELECT a FROM t WHERE d > DATE '2020-01-01';"
+    );
+}
+
+#[test]
+fn minimal_parentheses() {
+    for (sql, rendered) in [
+        ("(a AND b) AND c", "a AND b AND c"),
+        ("a AND (b AND c)", "a AND (b AND c)"),
+        ("a OR b AND c", "a OR b AND c"),
+        ("(a OR b) AND c", "(a OR b) AND c"),
+        ("(a + b) * c", "(a + b) * c"),
+        ("a + (b * c)", "a + b * c"),
+        ("(a - b) - c", "a - b - c"),
+        ("a - (b - c)", "a - (b - c)"),
+        ("-(a + b)", "-(a + b)"),
+        ("NOT (a = b)", "NOT (a = b)"),
+        ("NOT (a AND b)", "NOT (a AND b)"),
+    ] {
+        assert_eq!(expression_round_trip(sql), rendered, "{}", sql);
+    }
+}
+
+#[test]
+fn quotes_in_strings_round_trip() {
+    assert_eq!(expression_round_trip("name = 'O''Brien'"), "name = 'O''Brien'");
+}
+
+#[test]
+fn statements_round_trip() {
+    assert_eq!(
+        round_trip("select (a+b)*c, d from t where not (x = 1 or y < 2) order by d;"),
+        "SELECT (a + b) * c, d FROM t WHERE NOT (x = 1 OR y < 2) ORDER BY d;"
+    );
+    for sql in [
+        "CREATE TABLE t (id INT PRIMARY KEY, name VARCHAR(20) NOT NULL, price DECIMAL(10, 2) DEFAULT 0, created TIMESTAMP);",
+        "UPDATE t SET a = a - 1, b = 'x' WHERE c >= 2;",
+        "DROP TABLE t;",
+        "INSERT INTO t (a, b) VALUES (1, 'x'), (2, NULL);",
+    ] {
+        assert_eq!(round_trip(sql), sql);
+    }
+}
+
+#[test]
+fn column_definitions_display() {
+    match statement("CREATE TABLE t (price DECIMAL(10, 2) NOT NULL CHECK (price > 0));") {
+        Statement::CreateTable { column_list, .. } => {
+            let column = &column_list[0];
+            assert_eq!(column.to_string(), "price DECIMAL(10, 2) NOT NULL CHECK (price > 0)");
+            assert_eq!(column.column_type.to_string(), "DECIMAL(10, 2)");
+            assert_eq!(column.constraints[1].to_string(), "CHECK (price > 0)");
+        }
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+}
+
+#[test]
+fn concat_renders_per_dialect() {
+    let concat = expression("a || b");
+    assert_eq!(concat.to_string(), "a || b");
+    let mysql = SqlFormatter::new(FormatOptions { dialect: Dialect::MySql, ..Default::default() });
+    assert_eq!(mysql.format_expression(&concat), "a OR b");
+    assert_eq!(mysql.format_expression(&expression("a || b || c")), "a OR b OR c");
+    assert_eq!(mysql.format_expression(&expression("(a || b) = c")), "(a OR b) = c");
+
+    let or = Parser::with_dialect(Tokenizer::new("a || b"), Dialect::MySql).parse_expression().unwrap();
+    assert_eq!(mysql.format_expression(&or), "a OR b");
+}
+
+#[test]
+fn order_by_directions_follow_the_expression() {
+    assert_eq!(round_trip("SELECT a, b FROM t ORDER BY a DESC, b ASC;"), "SELECT a, b FROM t ORDER BY a DESC, b ASC;");
+    assert_eq!(round_trip("SELECT a FROM t ORDER BY -a DESC, a + 1;"), "SELECT a FROM t ORDER BY -a DESC, a + 1;");
+}
+
+#[test]
+fn operator_and_comma_spacing() {
+    let expr = expression("f(a+b,c*2)");
+    assert_eq!(SqlFormatter::default().format_expression(&expr), "f(a + b, c * 2)");
+    let tight = SqlFormatter::new(FormatOptions { tight_operators: true, tight_commas: true, ..Default::default() });
+    assert_eq!(tight.format_expression(&expr), "f(a+b,c*2)");
+    assert_eq!(tight.format_expression(&expression("a+b AND c")), "a+b AND c");
+    let tight_operators = SqlFormatter::new(FormatOptions { tight_operators: true, ..Default::default() });
+    assert_eq!(tight_operators.format_expression(&expr), "f(a+b, c*2)");
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::expression;
+use sql_parser::lint::check_like_escapes;
+
+#[test]
+fn trailing_backslash_is_reported() {
+    assert_eq!(
+        check_like_escapes(&expression(r"name LIKE 'abc\'")),
+        Some(r"LIKE pattern 'abc\' ends with a dangling escape character".to_string())
+    );
+}
+
+#[test]
+fn backslash_before_a_non_wildcard_is_reported() {
+    assert_eq!(
+        check_like_escapes(&expression(r"name LIKE 'a\bc'")),
+        Some(r"LIKE pattern 'a\bc' escapes 'b' at position 1, which is not a wildcard".to_string())
+    );
+}
+
+#[test]
+fn escaped_wildcards_pass() {
+    assert_eq!(check_like_escapes(&expression(r"name LIKE '100\% a\_b c\\d'")), None);
+    assert_eq!(check_like_escapes(&expression("name NOT LIKE 'abc%'")), None);
+}
+
+#[test]
+fn other_expressions_are_ignored() {
+    assert_eq!(check_like_escapes(&expression(r"name = 'abc\'")), None);
+    assert_eq!(check_like_escapes(&expression("name LIKE other")), None);
+}
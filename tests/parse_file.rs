@@ -0,0 +1,50 @@
+use sql_parser::error::ParseError;
+use sql_parser::parse_file;
+
+#[test]
+fn invalid_statement_after_a_valid_one() {
+    let input = "SELECT a FROM t; SELECT FROM;";
+    let statements = parse_file(input);
+    assert_eq!(statements.len(), 2);
+    let (first, first_span) = &statements[0];
+    assert!(first.is_ok(), "{:?}", first);
+    assert_eq!(&input[first_span.clone()], "SELECT a FROM t;");
+    let (second, second_span) = &statements[1];
+    assert!(second.is_err());
+    assert_eq!(&input[second_span.clone()], "SELECT FROM;");
+}
+
+#[test]
+fn tokenizer_error_span_covers_the_bad_character() {
+    let input = "SELECT a FROM t; SELECT @ FROM t;";
+    let statements = parse_file(input);
+    let (result, span) = statements.last().unwrap();
+    match result {
+        Err(ParseError::LexError(error)) => {
+            assert_eq!(error.position, 24);
+            assert!(span.contains(&error.position), "{:?}", span);
+        }
+        other => panic!("expected a tokenizer error, got {:?}", other),
+    }
+}
+
+#[test]
+fn tokenizer_error_after_the_last_statement() {
+    let statements = parse_file("SELECT a FROM t;@");
+    assert_eq!(statements.len(), 2);
+    assert!(matches!(statements[1], (Err(ParseError::LexError(_)), ref span) if *span == (16..17)));
+}
+
+#[test]
+fn spans_over_several_lines() {
+    let input = "-- schema\nCREATE TABLE t (a INT);\n\nSELECT a\nFROM t;\n";
+    let spans: Vec<&str> = parse_file(input)
+        .into_iter()
+        .map(|(result, span)| {
+            assert!(result.is_ok(), "{:?}", result);
+            &input[span]
+        })
+        .collect();
+    assert_eq!(spans, ["CREATE TABLE t (a INT);", "SELECT a\nFROM t;"]);
+    assert!(parse_file("  \n-- nothing here\n").is_empty());
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use common::ident;
+use sql_parser::error::ParseError;
+use sql_parser::parser::Parser;
+use sql_parser::statement::{BinaryOperator, Expression};
+use sql_parser::tokenizer::Tokenizer;
+
+#[test]
+fn restore_rewinds_a_speculative_parse() {
+    for (sql, is_subquery) in [("(SELECT a FROM t)", true), ("(1 + 2)", false)] {
+        let mut parser = Parser::new(Tokenizer::new(sql));
+        let checkpoint = parser.checkpoint();
+        let first = parser.parse_expression().unwrap();
+        assert_eq!(matches!(first, Expression::Subquery(_)), is_subquery, "{}", sql);
+        parser.restore(checkpoint);
+        assert_eq!(parser.parse_expression().unwrap(), first, "{}", sql);
+    }
+}
+
+#[test]
+fn restore_after_a_failed_parse() {
+    let mut parser = Parser::new(Tokenizer::new("(a + b)"));
+    let checkpoint = parser.checkpoint();
+    assert!(parser.parse_statement().is_err());
+    parser.restore(checkpoint);
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expression::BinaryOperation {
+            left_operand: Box::new(ident("a")),
+            operator: BinaryOperator::Plus,
+            right_operand: Box::new(ident("b")),
+        }
+    );
+}
+
+#[test]
+fn restore_after_tokens_were_handed_out() {
+    let mut parser = Parser::new(Tokenizer::new("a b || 'x'"));
+    assert_eq!(parser.parse_expression().unwrap(), ident("a"));
+    let checkpoint = parser.checkpoint();
+    let first = parser.parse_expression().unwrap();
+    parser.restore(checkpoint);
+    assert_eq!(parser.parse_expression().unwrap(), first);
+    assert_eq!(first.to_string(), "b || 'x'");
+}
+
+#[test]
+fn statements_after_failed_ones_keep_their_names() {
+    let input = "SELECT a FROM ;\n\
+                 SELECT 'x', name FROM t WHERE b IS NOT NULL;\n\
+                 SELECT f(a, ) FROM t;\n\
+                 SELECT c FROM u WHERE d IS DISTINCT FROM 'y';";
+    let results: Vec<_> = Parser::new(Tokenizer::new(input))
+        .parse_statements()
+        .into_iter()
+        .map(|(result, span)| (result.map(|statement| statement.to_string()), span))
+        .collect();
+    assert_eq!(results.len(), 4);
+    assert!(results[0].0.is_err());
+    assert_eq!(results[1].0.as_deref(), Ok("SELECT 'x', name FROM t WHERE b IS NOT NULL;"));
+    assert!(matches!(results[2].0, Err(ParseError::UnexpectedToken { .. })), "{:?}", results[2].0);
+    assert_eq!(results[3].0.as_deref(), Ok("SELECT c FROM u WHERE d IS DISTINCT FROM 'y';"));
+    assert_eq!(&input[results[3].1.clone()], "SELECT c FROM u WHERE d IS DISTINCT FROM 'y';");
+}
+
+#[test]
+fn parses_consecutive_statements() {
+    let input = "CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(20) NOT NULL DEFAULT 'anon');\n\
+                 INSERT INTO users (id, name) VALUES (1, 'O''Brien'), (2, 'Lee');\n\
+                 UPDATE users SET name = 'x' WHERE id IN (1, 2);\n\
+                 SELECT u.name, COUNT(*) FROM users u GROUP BY u.name ORDER BY u.name;\n\
+                 DROP TABLE users;";
+    let rendered: Vec<String> = Parser::new(Tokenizer::new(input))
+        .parse_statements()
+        .into_iter()
+        .map(|(result, _)| result.unwrap().to_string())
+        .collect();
+    assert_eq!(
+        rendered,
+        [
+            "CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(20) NOT NULL DEFAULT 'anon');",
+            "INSERT INTO users (id, name) VALUES (1, 'O''Brien'), (2, 'Lee');",
+            "UPDATE users SET name = 'x' WHERE id IN (1, 2);",
+            "SELECT u.name, COUNT(*) FROM users AS u GROUP BY u.name ORDER BY u.name;",
+            "DROP TABLE users;",
+        ]
+    );
+}
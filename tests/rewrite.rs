@@ -0,0 +1,96 @@
+mod common;
+
+use common::{expression, statement};
+use sql_parser::rewrite::simplify_not;
+use sql_parser::statement::Expression;
+
+#[test]
+fn rename_alias_across_select() {
+    let mut select = statement("SELECT u.name FROM users u JOIN orders o ON o.user_id = u.id WHERE u.id = 1 ORDER BY u.name;");
+    select.rename_alias("u", "users");
+    assert_eq!(
+        select,
+        statement("SELECT users.name FROM users users JOIN orders o ON o.user_id = users.id WHERE users.id = 1 ORDER BY users.name;")
+    );
+}
+
+#[test]
+fn rename_alias_leaves_other_qualifiers() {
+    let mut select = statement("SELECT u.id, o.id, id FROM users u JOIN orders o ON o.user_id = u.id;");
+    select.rename_alias("x", "y");
+    assert_eq!(select, statement("SELECT u.id, o.id, id FROM users u JOIN orders o ON o.user_id = u.id;"));
+    select.rename_alias("o", "ord");
+    assert_eq!(select.to_string(), "SELECT u.id, ord.id, id FROM users AS u INNER JOIN orders AS ord ON ord.user_id = u.id;");
+}
+
+#[test]
+fn rename_alias_in_subquery() {
+    let mut select = statement("SELECT u.id FROM users u WHERE EXISTS (SELECT 1 FROM orders o WHERE o.user_id = u.id);");
+    select.rename_alias("o", "ord");
+    assert_eq!(select, statement("SELECT u.id FROM users u WHERE EXISTS (SELECT 1 FROM orders ord WHERE ord.user_id = u.id);"));
+}
+
+fn simplified(sql: &str) -> String {
+    simplify_not(expression(sql)).to_string()
+}
+
+#[test]
+fn simplify_not_negates_each_comparison() {
+    for (sql, negated) in [
+        ("NOT (a = b)", "a != b"),
+        ("NOT (a != b)", "a = b"),
+        ("NOT (a < b)", "a >= b"),
+        ("NOT (a <= b)", "a > b"),
+        ("NOT (a > b)", "a <= b"),
+        ("NOT (a >= b)", "a < b"),
+    ] {
+        assert_eq!(simplified(sql), negated, "{}", sql);
+    }
+}
+
+#[test]
+fn simplify_not_leaves_other_operands() {
+    assert_eq!(simplified("NOT (a AND b)"), "NOT (a AND b)");
+    assert_eq!(simplified("NOT a"), "NOT a");
+    assert_eq!(simplified("NOT (a + b)"), "NOT (a + b)");
+    assert_eq!(simplified("x OR NOT (NOT (a = 1))"), "x OR a = 1");
+}
+
+#[test]
+fn map_replaces_identifiers_in_place() {
+    let mut expr = expression("a + f(a, b) * CASE WHEN a > 1 THEN a END");
+    expr.map(&mut |e: &mut Expression| {
+        if *e == Expression::Identifier("a".to_string()) {
+            *e = Expression::Identifier("b".to_string());
+        }
+    });
+    assert_eq!(expr, expression("b + f(b, b) * CASE WHEN b > 1 THEN b END"));
+}
+
+#[test]
+fn map_visits_children_before_their_parent() {
+    let mut expr = expression("(1 + 2) * 3");
+    let mut visited = Vec::new();
+    expr.map(&mut |e: &mut Expression| visited.push(e.to_string()));
+    assert_eq!(visited, ["1", "2", "1 + 2", "3", "(1 + 2) * 3"]);
+}
+
+#[test]
+fn rewrite_literals_redacts_strings() {
+    let mut insert = statement("INSERT INTO users (name, age, email) VALUES ('alice', 30, 'a@example.com');");
+    insert.rewrite_literals(|e| matches!(e, Expression::String(_)).then(|| Expression::String("***".to_string())));
+    assert_eq!(insert.to_string(), "INSERT INTO users (name, age, email) VALUES ('***', 30, '***');");
+}
+
+#[test]
+fn rewrite_literals_visits_every_clause() {
+    let mut select = statement(
+        "SELECT a + 1 FROM t WHERE b = 'x' AND c IN (SELECT d FROM u WHERE e > 2) GROUP BY a HAVING COUNT(*) > 3 LIMIT 4;",
+    );
+    let mut seen = Vec::new();
+    select.rewrite_literals(|e| {
+        seen.push(e.to_string());
+        Some(Expression::Identifier("?".to_string()))
+    });
+    assert_eq!(seen, ["1", "'x'", "2", "3", "4"]);
+}
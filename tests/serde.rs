@@ -0,0 +1,26 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::statement;
+use sql_parser::statement::Statement;
+
+#[test]
+fn statements_round_trip_through_json() {
+    for sql in [
+        "SELECT a AS x, f(1.5) FROM t WHERE b LIKE 'x%' ORDER BY a DESC;",
+        "CREATE TABLE t (id INT PRIMARY KEY, name VARCHAR(20) NOT NULL DEFAULT 'n', price DECIMAL(10, 2) CHECK (price > 0));",
+        "UPDATE t SET a = -1 WHERE b IS NOT NULL;",
+    ] {
+        let parsed = statement(sql);
+        let json = serde_json::to_string(&parsed).unwrap();
+        let back: Statement = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, parsed, "{}", json);
+    }
+}
+
+#[test]
+fn enums_are_externally_tagged() {
+    let json = serde_json::to_value(statement("DROP TABLE t;")).unwrap();
+    assert_eq!(json["DropTable"]["table_names"], serde_json::json!(["t"]));
+}
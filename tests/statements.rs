@@ -0,0 +1,757 @@
+mod common;
+
+use common::{dialect_statement, ident, round_trip, statement, statement_error};
+use sql_parser::dialect::Dialect;
+use sql_parser::error::ParseError;
+use sql_parser::statement::{AlterAction, BinaryOperator, Constraint, DBType, DropBehavior, Expression, FetchDirection, FrameExclusion, InsertSource, JoinType, MatchType, OrderByItem, ReferentialAction, Statement, TableColumn, TableConstraint, TableRef, WhereClause, Window};
+
+fn from_table(statement: &Statement) -> &TableRef {
+    match statement {
+        Statement::Select { from, .. } => from,
+        other => panic!("expected a SELECT, got {:?}", other),
+    }
+}
+
+#[test]
+fn from_alias_without_as() {
+    let select = statement("SELECT u.name FROM users u;");
+    assert_eq!(from_table(&select), &TableRef { name: "users".to_string(), alias: Some("u".to_string()) });
+    assert_eq!(round_trip("SELECT u.name FROM users u;"), "SELECT u.name FROM users AS u;");
+}
+
+#[test]
+fn from_alias_with_as() {
+    let select = statement("SELECT u.name FROM users AS u WHERE u.id = 1;");
+    assert_eq!(from_table(&select), &TableRef { name: "users".to_string(), alias: Some("u".to_string()) });
+    assert_eq!(from_table(&statement("SELECT name FROM users;")).alias, None);
+}
+
+#[test]
+fn from_alias_in_dot_export() {
+    assert!(statement("SELECT u.name FROM users u;").to_dot().contains("FROM users AS u"));
+}
+
+fn columns(sql: &str) -> Vec<TableColumn> {
+    match statement(sql) {
+        Statement::CreateTable { column_list, .. } => column_list,
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+}
+
+#[test]
+fn check_constraint_without_subquery() {
+    statement("CREATE TABLE t (a INT CHECK (a > 0 AND a IN (1, 2)));");
+}
+
+#[test]
+fn check_constraint_rejects_subqueries() {
+    for sql in [
+        "CREATE TABLE t (a INT CHECK (a IN (SELECT x FROM y)));",
+        "CREATE TABLE t (a INT CHECK (a > (SELECT 1 FROM y)));",
+        "CREATE TABLE t (a INT CHECK (NOT EXISTS (SELECT 1 FROM y)));",
+    ] {
+        assert_eq!(
+            statement_error(sql),
+            ParseError::Invalid("subqueries are not allowed in CHECK constraints".to_string()),
+            "{}",
+            sql
+        );
+    }
+}
+
+#[test]
+fn float_in_check_constraint() {
+    match statement("CREATE TABLE t (price FLOAT CHECK (price >= 0.01));") {
+        Statement::CreateTable { column_list, .. } => match &column_list[0].constraints[..] {
+            [Constraint::Check(Expression::BinaryOperation { right_operand, .. })] => {
+                assert_eq!(**right_operand, Expression::Float(0.01))
+            }
+            other => panic!("expected a CHECK comparison, got {:?}", other),
+        },
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+}
+
+#[test]
+fn select_distinct() {
+    match statement("SELECT DISTINCT a, b FROM t;") {
+        Statement::Select { distinct, columns, .. } => {
+            assert!(distinct);
+            assert_eq!(columns.len(), 2);
+        }
+        other => panic!("expected a SELECT, got {:?}", other),
+    }
+    assert!(matches!(statement("SELECT a FROM t;"), Statement::Select { distinct: false, .. }));
+    assert_eq!(round_trip("select distinct a from t"), "SELECT DISTINCT a FROM t;");
+}
+
+#[test]
+fn distinct_inside_an_expression_is_rejected() {
+    assert_eq!(
+        statement_error("SELECT a + DISTINCT b FROM t;"),
+        ParseError::Invalid("DISTINCT is only allowed after SELECT or inside an aggregate function".to_string())
+    );
+}
+
+fn order_by(sql: &str) -> Vec<OrderByItem> {
+    match statement(sql) {
+        Statement::Select { orderby, .. } => orderby,
+        other => panic!("expected a SELECT, got {:?}", other),
+    }
+}
+
+#[test]
+fn order_by_simple_columns() {
+    let items = order_by("SELECT a FROM t ORDER BY b, t.b DESC, a + b, t.*;");
+    let simple: Vec<bool> = items.iter().map(OrderByItem::is_simple_column).collect();
+    assert_eq!(simple, vec![true, true, false, false]);
+}
+
+#[test]
+fn references_with_referential_actions() {
+    let columns = columns("CREATE TABLE t (a INT REFERENCES u(id) ON DELETE CASCADE ON UPDATE RESTRICT MATCH FULL);");
+    assert_eq!(
+        columns[0].constraints,
+        vec![Constraint::References {
+            table: "u".to_string(),
+            column: Some("id".to_string()),
+            on_delete: Some(ReferentialAction::Cascade),
+            on_update: Some(ReferentialAction::Restrict),
+            match_type: Some(MatchType::Full),
+        }]
+    );
+}
+
+#[test]
+fn references_with_multi_word_actions() {
+    match &columns("CREATE TABLE t (a INT REFERENCES u ON UPDATE SET NULL ON DELETE NO ACTION MATCH SIMPLE);")[0].constraints[..] {
+        [Constraint::References { column, on_delete, on_update, match_type, .. }] => {
+            assert_eq!(*column, None);
+            assert_eq!(*on_delete, Some(ReferentialAction::NoAction));
+            assert_eq!(*on_update, Some(ReferentialAction::SetNull));
+            assert_eq!(*match_type, Some(MatchType::Simple));
+        }
+        other => panic!("expected a REFERENCES constraint, got {:?}", other),
+    }
+    round_trip("CREATE TABLE t (a INT REFERENCES u(id) ON DELETE SET NULL ON UPDATE CASCADE MATCH PARTIAL);");
+}
+
+#[test]
+fn references_rejects_repeated_actions() {
+    statement_error("CREATE TABLE t (a INT REFERENCES u ON DELETE CASCADE ON DELETE RESTRICT);");
+}
+
+fn returning(sql: &str) -> Vec<Expression> {
+    match statement(sql) {
+        Statement::Insert { returning, .. } => returning,
+        other => panic!("expected INSERT, got {:?}", other),
+    }
+}
+
+#[test]
+fn returning_with_aliases() {
+    assert_eq!(
+        returning("INSERT INTO t (a) VALUES (1) RETURNING id AS new_id, b;"),
+        vec![
+            Expression::Aliased { expr: Box::new(ident("id")), alias: "new_id".to_string() },
+            ident("b"),
+        ]
+    );
+    round_trip("INSERT INTO t (a) VALUES (1) RETURNING id AS new_id, b;");
+}
+
+#[test]
+fn returning_wildcard() {
+    assert_eq!(returning("INSERT INTO t VALUES (1) RETURNING *;"), vec![ident("*")]);
+}
+
+#[test]
+fn insert_values_match_the_column_list() {
+    statement("INSERT INTO t (a, b) VALUES (1, 2), (3, 4);");
+    statement("INSERT INTO t VALUES (1, 2, 3);");
+}
+
+#[test]
+fn insert_value_count_mismatch() {
+    assert_eq!(
+        statement_error("INSERT INTO t (a, b) VALUES (1, 2, 3);"),
+        ParseError::Invalid("INSERT has 2 columns but 3 values".to_string())
+    );
+    assert_eq!(
+        statement_error("INSERT INTO t (a, b) VALUES (1, 2), (3);"),
+        ParseError::Invalid("INSERT has 2 columns but 1 values".to_string())
+    );
+}
+
+#[test]
+fn column_nullability() {
+    let columns = columns("CREATE TABLE t (id INT PRIMARY KEY, name VARCHAR(20) NOT NULL, note TEXT, age INT DEFAULT 0);");
+    let nullable: Vec<bool> = columns.iter().map(|column| column.is_nullable()).collect();
+    assert_eq!(nullable, [false, false, true, true]);
+}
+
+#[test]
+fn mysql_column_comment() {
+    match dialect_statement("CREATE TABLE t (name VARCHAR(20) NOT NULL COMMENT 'the name', b INT);", Dialect::MySql) {
+        Statement::CreateTable { column_list, .. } => {
+            assert_eq!(column_list[0].comment.as_deref(), Some("the name"));
+            assert_eq!(column_list[0].constraints, vec![Constraint::NotNull]);
+            assert_eq!(column_list[1].comment, None);
+        }
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+}
+
+#[test]
+fn column_comment_requires_mysql() {
+    statement_error("CREATE TABLE t (name VARCHAR(20) COMMENT 'the name');");
+}
+
+#[test]
+fn comment_is_a_column_name() {
+    assert!(matches!(statement("SELECT comment FROM t;"), Statement::Select { columns, .. } if columns == vec![ident("comment")]));
+    let generic = columns("CREATE TABLE t (comment VARCHAR(100), b INT);");
+    assert_eq!(generic[0].column_name, "comment");
+    assert_eq!(generic[0].comment, None);
+    match dialect_statement("CREATE TABLE t (comment VARCHAR(100) COMMENT 'note');", Dialect::MySql) {
+        Statement::CreateTable { column_list, .. } => {
+            assert_eq!(column_list[0].column_name, "comment");
+            assert_eq!(column_list[0].comment.as_deref(), Some("note"));
+        }
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+    round_trip("CREATE TABLE t (comment VARCHAR(100));");
+}
+
+#[test]
+fn update_where_current_of() {
+    match statement("UPDATE t SET a = 1 WHERE CURRENT OF cur;") {
+        Statement::Update { r#where, .. } => assert_eq!(r#where, Some(WhereClause::CurrentOf("cur".to_string()))),
+        other => panic!("expected UPDATE, got {:?}", other),
+    }
+    assert!(matches!(
+        statement("UPDATE t SET a = 1 WHERE current_value > 1;"),
+        Statement::Update { r#where: Some(WhereClause::Expression(_)), .. }
+    ));
+    round_trip("UPDATE t SET a = 1 WHERE CURRENT OF cur;");
+}
+
+#[test]
+fn group_by_two_columns() {
+    match statement("SELECT dept, role, COUNT(*) FROM emp GROUP BY dept, role HAVING COUNT(*) > 1;") {
+        Statement::Select { groupby, having, .. } => {
+            assert_eq!(groupby, vec![ident("dept"), ident("role")]);
+            assert!(having.is_some());
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    round_trip("SELECT dept, COUNT(*) FROM emp GROUP BY dept ORDER BY dept;");
+}
+
+#[test]
+fn named_window_reference() {
+    match statement("SELECT a, RANK() OVER w FROM t WINDOW w AS (PARTITION BY b ORDER BY x);") {
+        Statement::Select { columns, windows, .. } => {
+            assert!(
+                matches!(&columns[1], Expression::WindowFunction { window: Window::Named(name), .. } if name == "w"),
+                "{:?}",
+                columns[1]
+            );
+            assert_eq!(windows.len(), 1);
+            assert_eq!(windows[0].0, "w");
+            assert_eq!(windows[0].1.partition_by, vec![ident("b")]);
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    round_trip("SELECT RANK() OVER w FROM t WINDOW w AS (ORDER BY x);");
+}
+
+#[test]
+fn window_frame_exclusion() {
+    let sql = "SELECT SUM(a) OVER (ORDER BY x ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW) FROM t;";
+    match statement(sql) {
+        Statement::Select { columns, .. } => match &columns[0] {
+            Expression::WindowFunction { window: Window::Spec(spec), .. } => {
+                assert_eq!(spec.frame.as_ref().and_then(|frame| frame.exclude.clone()), Some(FrameExclusion::CurrentRow))
+            }
+            other => panic!("expected a window function, got {:?}", other),
+        },
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    assert_eq!(round_trip(sql), sql);
+}
+
+#[test]
+fn wildcard_except() {
+    match dialect_statement("SELECT * EXCEPT (a, b) FROM users;", Dialect::BigQuery) {
+        Statement::Select { columns, wildcard_except, .. } => {
+            assert_eq!(columns, vec![ident("*")]);
+            assert_eq!(wildcard_except, vec!["a".to_string(), "b".to_string()]);
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    dialect_statement("SELECT * EXCEPT (password) FROM users;", Dialect::DuckDb);
+    statement_error("SELECT * EXCEPT (password) FROM users;");
+}
+
+#[test]
+fn wildcard_replace() {
+    match dialect_statement("SELECT * REPLACE (UPPER(name) AS name, a + 1 AS a) FROM t;", Dialect::DuckDb) {
+        Statement::Select { wildcard_replace, .. } => {
+            let aliases: Vec<&str> = wildcard_replace.iter().map(|(_, alias)| alias.as_str()).collect();
+            assert_eq!(aliases, ["name", "a"]);
+            assert!(matches!(&wildcard_replace[0].0, Expression::FunctionCall { name, .. } if name == "UPPER"));
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    statement_error("SELECT * REPLACE (UPPER(name) AS name) FROM t;");
+}
+
+#[test]
+fn column_aliases() {
+    match statement("SELECT price * quantity AS total, name n FROM orders;") {
+        Statement::Select { columns, .. } => {
+            assert!(matches!(&columns[0], Expression::Aliased { alias, .. } if alias == "total"), "{:?}", columns[0]);
+            assert_eq!(columns[1], Expression::Aliased { expr: Box::new(ident("name")), alias: "n".to_string() });
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    assert_eq!(
+        round_trip("SELECT price * quantity total FROM orders;"),
+        "SELECT price * quantity AS total FROM orders;"
+    );
+}
+
+#[test]
+fn boolean_is_bool() {
+    assert_eq!(columns("CREATE TABLE t (active BOOLEAN);")[0].column_type, DBType::Bool);
+    assert_eq!(statement("CREATE TABLE t (active BOOLEAN);"), statement("CREATE TABLE t (active BOOL);"));
+}
+
+fn join_types(sql: &str) -> Vec<JoinType> {
+    match statement(sql) {
+        Statement::Select { joins, .. } => joins.into_iter().map(|join| join.join_type).collect(),
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+}
+
+#[test]
+fn outer_joins() {
+    assert_eq!(join_types("SELECT a FROM t LEFT OUTER JOIN u ON t.id = u.id;"), [JoinType::Left]);
+    assert_eq!(
+        statement("SELECT a FROM t LEFT OUTER JOIN u ON t.id = u.id;"),
+        statement("SELECT a FROM t LEFT JOIN u ON t.id = u.id;")
+    );
+    assert_eq!(
+        join_types("SELECT a FROM t JOIN u ON t.id = u.id RIGHT JOIN v ON v.id = u.id FULL OUTER JOIN w ON w.id = v.id;"),
+        [JoinType::Inner, JoinType::Right, JoinType::Full]
+    );
+    statement_error("SELECT a FROM t LEFT JOIN u;");
+}
+
+#[test]
+fn postgres_serial_types() {
+    let sql = "CREATE TABLE t (id SERIAL, big BIGSERIAL, small SMALLSERIAL);";
+    let column_list = match dialect_statement(sql, Dialect::Postgres) {
+        Statement::CreateTable { column_list, .. } => column_list,
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    };
+    let types: Vec<DBType> = column_list.iter().map(|column| column.column_type.clone()).collect();
+    assert_eq!(types, [DBType::Int, DBType::BigInt, DBType::SmallInt]);
+    for column in &column_list {
+        assert_eq!(column.constraints, vec![Constraint::AutoIncrement], "{}", column.column_name);
+    }
+    statement_error("CREATE TABLE t (id SERIAL);");
+}
+
+#[test]
+fn create_temporary_table() {
+    for sql in ["CREATE TEMPORARY TABLE t (a INT);", "CREATE TEMP TABLE t (a INT);"] {
+        assert!(matches!(statement(sql), Statement::CreateTable { temporary: true, .. }), "{}", sql);
+    }
+    assert!(matches!(statement("CREATE TABLE t (a INT);"), Statement::CreateTable { temporary: false, .. }));
+    round_trip("CREATE TEMPORARY TABLE t (a INT);");
+}
+
+#[test]
+fn mysql_table_options() {
+    match dialect_statement("CREATE TABLE t (a INT) ENGINE=InnoDB;", Dialect::MySql) {
+        Statement::CreateTable { options, .. } => assert_eq!(options, vec![("ENGINE".to_string(), "InnoDB".to_string())]),
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+    statement_error("CREATE TABLE t (a INT) ENGINE=InnoDB;");
+}
+
+#[test]
+fn negative_limit_and_offset_are_rejected() {
+    assert_eq!(
+        statement_error("SELECT a FROM t LIMIT -1;"),
+        ParseError::Invalid("LIMIT must be non-negative".to_string())
+    );
+    assert_eq!(
+        statement_error("SELECT a FROM t LIMIT 10 OFFSET -1;"),
+        ParseError::Invalid("OFFSET must be non-negative".to_string())
+    );
+    match statement("SELECT a FROM t LIMIT 0;") {
+        Statement::Select { limit, .. } => assert_eq!(limit, Some(Expression::Number(0))),
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+}
+
+#[test]
+fn drop_several_tables() {
+    assert_eq!(
+        statement("DROP TABLE IF EXISTS a, b, c;"),
+        Statement::DropTable {
+            table_names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            if_exists: true,
+            drop_behavior: None,
+        }
+    );
+    statement_error("DROP TABLE a, b,;");
+    statement_error("DROP TABLE a,");
+}
+
+#[test]
+fn unique_alongside_not_null() {
+    assert_eq!(
+        columns("CREATE TABLE t (email VARCHAR(255) NOT NULL UNIQUE);")[0].constraints,
+        vec![Constraint::NotNull, Constraint::Unique]
+    );
+    assert_eq!(
+        columns("CREATE TABLE t (email VARCHAR(255) UNIQUE NOT NULL);")[0].constraints,
+        vec![Constraint::Unique, Constraint::NotNull]
+    );
+}
+
+fn drop_behavior(sql: &str) -> Option<DropBehavior> {
+    match statement(sql) {
+        Statement::DropTable { drop_behavior, .. } => drop_behavior,
+        other => panic!("expected DROP TABLE, got {:?}", other),
+    }
+}
+
+#[test]
+fn drop_table_behavior() {
+    assert_eq!(drop_behavior("DROP TABLE t CASCADE;"), Some(DropBehavior::Cascade));
+    assert_eq!(drop_behavior("DROP TABLE a, b RESTRICT;"), Some(DropBehavior::Restrict));
+    assert_eq!(drop_behavior("DROP TABLE t;"), None);
+    round_trip("DROP TABLE IF EXISTS a, b CASCADE;");
+}
+
+#[test]
+fn default_values() {
+    let columns = columns("CREATE TABLE t (created_at INT DEFAULT 0, active BOOL DEFAULT TRUE NOT NULL, name TEXT DEFAULT 'none');");
+    assert_eq!(columns[0].constraints, vec![Constraint::Default(Expression::Number(0))]);
+    assert_eq!(columns[1].constraints, vec![Constraint::Default(Expression::Bool(true)), Constraint::NotNull]);
+    assert_eq!(columns[2].constraints, vec![Constraint::Default(Expression::String("none".to_string()))]);
+}
+
+#[test]
+fn references_with_and_without_a_column() {
+    let reference = |column: Option<&str>| Constraint::References {
+        table: "users".to_string(),
+        column: column.map(str::to_string),
+        on_delete: None,
+        on_update: None,
+        match_type: None,
+    };
+    assert_eq!(columns("CREATE TABLE t (user_id INT REFERENCES users);")[0].constraints, vec![reference(None)]);
+    assert_eq!(
+        columns("CREATE TABLE t (user_id INT NOT NULL REFERENCES users(id));")[0].constraints,
+        vec![Constraint::NotNull, reference(Some("id"))]
+    );
+}
+
+#[test]
+fn fetch_and_move() {
+    assert_eq!(
+        statement("FETCH NEXT FROM cur;"),
+        Statement::Fetch { direction: Some(FetchDirection::Next), cursor: "cur".to_string() }
+    );
+    assert_eq!(
+        statement("FETCH 10 FROM cur;"),
+        Statement::Fetch { direction: Some(FetchDirection::Count(10)), cursor: "cur".to_string() }
+    );
+    assert_eq!(
+        statement("FETCH ABSOLUTE -2 IN cur;"),
+        Statement::Fetch { direction: Some(FetchDirection::Absolute(-2)), cursor: "cur".to_string() }
+    );
+    assert_eq!(statement("MOVE cur;"), Statement::Move { direction: None, cursor: "cur".to_string() });
+    assert_eq!(round_trip("MOVE PRIOR FROM cur;"), "MOVE PRIOR FROM cur;");
+    assert_eq!(
+        statement("fetch last from cur;"),
+        Statement::Fetch { direction: Some(FetchDirection::Last), cursor: "cur".to_string() }
+    );
+}
+
+#[test]
+fn fetch_directions_are_names_elsewhere() {
+    match statement("SELECT first, last, next FROM people;") {
+        Statement::Select { columns, .. } => assert_eq!(columns, vec![ident("first"), ident("last"), ident("next")]),
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    assert_eq!(round_trip("SELECT prior, absolute, relative FROM t;"), "SELECT prior, absolute, relative FROM t;");
+}
+
+#[test]
+fn unbalanced_parentheses() {
+    assert_eq!(statement_error("SELECT (a + b FROM t;"), ParseError::UnbalancedParentheses { position: 7 });
+    assert_eq!(statement_error("SELECT a + b) FROM t;"), ParseError::UnbalancedParentheses { position: 12 });
+    assert_eq!(
+        statement_error("SELECT f((a), ')' FROM t;"),
+        ParseError::UnbalancedParentheses { position: 8 }
+    );
+    assert!(matches!(statement_error("SELECT (a + ) FROM t;"), ParseError::UnexpectedToken { .. }));
+}
+
+#[test]
+fn table_level_primary_key() {
+    match statement("CREATE TABLE t (a INT, b INT, PRIMARY KEY (a, b));") {
+        Statement::CreateTable { column_list, table_constraints, .. } => {
+            assert_eq!(column_list.len(), 2);
+            assert_eq!(
+                table_constraints,
+                vec![TableConstraint::PrimaryKey { name: None, columns: vec!["a".to_string(), "b".to_string()] }]
+            );
+        }
+        other => panic!("expected CREATE TABLE, got {:?}", other),
+    }
+    round_trip("CREATE TABLE t (a INT, b INT, PRIMARY KEY (a, b));");
+}
+
+fn column_types(sql: &str) -> Vec<DBType> {
+    columns(sql).into_iter().map(|column| column.column_type).collect()
+}
+
+#[test]
+fn float_and_double_columns() {
+    assert_eq!(column_types("CREATE TABLE t (x FLOAT, y DOUBLE);"), [DBType::Float, DBType::Double]);
+}
+
+#[test]
+fn decimal_columns() {
+    assert_eq!(
+        column_types("CREATE TABLE t (amount DECIMAL(10, 2), whole DECIMAL(10));"),
+        [DBType::Decimal { precision: 10, scale: 2 }, DBType::Decimal { precision: 10, scale: 0 }]
+    );
+    statement_error("CREATE TABLE t (amount DECIMAL);");
+}
+
+#[test]
+fn lenient_and_strict_semicolons() {
+    assert!(sql_parser::parse("SELECT a FROM t").is_ok());
+    assert!(sql_parser::parse_strict("SELECT a FROM t").is_err());
+    assert_eq!(sql_parser::parse("SELECT a FROM t;"), sql_parser::parse_strict("SELECT a FROM t;"));
+    assert!(sql_parser::parse_strict("SELECT a FROM t;").is_ok());
+    assert!(sql_parser::parse("SELECT a FROM t;;").is_err());
+    assert!(sql_parser::parse("SELECT a FROM t; SELECT b FROM t;").is_err());
+}
+
+#[test]
+fn date_and_timestamp_columns() {
+    assert_eq!(
+        column_types("CREATE TABLE t (created DATE, updated TIMESTAMP);"),
+        [DBType::Date, DBType::Timestamp]
+    );
+}
+
+#[test]
+fn date_and_time_as_column_names() {
+    let columns = columns("CREATE TABLE t (date DATE, time TIME, timestamp TIMESTAMP);");
+    assert_eq!(columns[0].column_name, "date");
+    assert_eq!(columns[0].column_type, DBType::Date);
+    assert_eq!(columns[1].column_name, "time");
+    assert_eq!(columns[2].column_name, "timestamp");
+    match statement("SELECT date FROM t WHERE date > DATE '2024-01-01';") {
+        Statement::Select { columns, r#where, .. } => {
+            assert_eq!(columns, vec![ident("date")]);
+            assert!(matches!(r#where, Some(Expression::BinaryOperation { left_operand, .. }) if *left_operand == ident("date")));
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    round_trip("SELECT date, time FROM t WHERE date > DATE '2024-01-01';");
+}
+
+fn alter_action(sql: &str) -> AlterAction {
+    match statement(sql) {
+        Statement::AlterTable { action, .. } => action,
+        other => panic!("expected ALTER TABLE, got {:?}", other),
+    }
+}
+
+#[test]
+fn alter_table_add_constraint() {
+    assert_eq!(
+        alter_action("ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES b(id);"),
+        AlterAction::AddConstraint(TableConstraint::ForeignKey {
+            name: Some("fk".to_string()),
+            columns: vec!["a".to_string()],
+            table: "b".to_string(),
+            referenced_columns: vec!["id".to_string()],
+            on_delete: None,
+            on_update: None,
+            match_type: None,
+        })
+    );
+    assert_eq!(
+        alter_action("ALTER TABLE t ADD PRIMARY KEY (a, b);"),
+        AlterAction::AddConstraint(TableConstraint::PrimaryKey { name: None, columns: vec!["a".to_string(), "b".to_string()] })
+    );
+    round_trip("ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES b(id) ON DELETE CASCADE;");
+}
+
+#[test]
+fn char_and_text_columns() {
+    assert_eq!(column_types("CREATE TABLE t (code CHAR(5), body TEXT);"), [DBType::Char(5), DBType::Text]);
+    statement_error("CREATE TABLE t (code CHAR);");
+}
+
+#[test]
+fn text_as_column_name() {
+    let columns = columns("CREATE TABLE t (text TEXT);");
+    assert_eq!(columns[0].column_name, "text");
+    assert_eq!(columns[0].column_type, DBType::Text);
+    match statement("SELECT text FROM t ORDER BY text;") {
+        Statement::Select { columns, .. } => assert_eq!(columns, vec![ident("text")]),
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    round_trip("SELECT upper(text) AS text FROM t;");
+}
+
+#[test]
+fn alter_table_drop_constraint() {
+    assert_eq!(alter_action("ALTER TABLE t DROP CONSTRAINT fk;"), AlterAction::DropConstraint("fk".to_string()));
+    assert!(matches!(
+        dialect_statement("ALTER TABLE t DROP PRIMARY KEY;", Dialect::MySql),
+        Statement::AlterTable { action: AlterAction::DropPrimaryKey, .. }
+    ));
+    statement_error("ALTER TABLE t DROP PRIMARY KEY;");
+    statement_error("ALTER TABLE t DROP CONSTRAINT;");
+}
+
+#[test]
+fn quoted_identifiers_as_column_names() {
+    match statement("SELECT \"order\", `group` FROM t;") {
+        Statement::Select { columns, .. } => assert_eq!(columns, vec![ident("order"), ident("group")]),
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    assert_eq!(round_trip("SELECT \"order\", `first name` FROM t;"), "SELECT \"order\", \"first name\" FROM t;");
+}
+
+#[test]
+fn null_as_a_value() {
+    match statement("SELECT NULL, 1 FROM t WHERE x = NULL;") {
+        Statement::Select { columns, r#where, .. } => {
+            assert_eq!(columns, vec![Expression::Null, Expression::Number(1)]);
+            assert!(
+                matches!(&r#where, Some(Expression::BinaryOperation { right_operand, .. }) if **right_operand == Expression::Null)
+            );
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+}
+
+#[test]
+fn limit_and_offset_expressions() {
+    match dialect_statement("SELECT a FROM t LIMIT (SELECT COUNT(*) FROM u) OFFSET n + 1;", Dialect::Postgres) {
+        Statement::Select { limit, offset, .. } => {
+            assert!(matches!(limit, Some(Expression::Subquery(_))), "{:?}", limit);
+            assert!(matches!(offset, Some(Expression::BinaryOperation { .. })), "{:?}", offset);
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    match statement("SELECT a FROM t LIMIT 10 OFFSET 5;") {
+        Statement::Select { limit, offset, .. } => {
+            assert_eq!(limit, Some(Expression::Number(10)));
+            assert_eq!(offset, Some(Expression::Number(5)));
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    assert_eq!(
+        statement_error("SELECT a FROM t LIMIT a + 1;"),
+        ParseError::Invalid("LIMIT must be a number, expressions are not supported in the generic dialect".to_string())
+    );
+    assert!(matches!(statement_error("SELECT a FROM t LIMIT 10 OFFSET (SELECT b FROM u);"), ParseError::Invalid(_)));
+    assert_eq!(
+        sql_parser::parse_with_dialect("SELECT a FROM t LIMIT -1;", Dialect::Postgres),
+        Err(ParseError::Invalid("LIMIT must be non-negative".to_string()))
+    );
+    assert_eq!(
+        dialect_statement("SELECT a FROM t LIMIT a + 1 OFFSET 2;", Dialect::Postgres).to_string(),
+        "SELECT a FROM t LIMIT a + 1 OFFSET 2;"
+    );
+}
+
+#[test]
+fn statements_compare_and_clone() {
+    let sql = "SELECT a, COUNT(DISTINCT b) AS n FROM t JOIN u ON t.id = u.id WHERE c IN (1, 2) GROUP BY a ORDER BY a DESC LIMIT 5;";
+    assert_eq!(statement(sql), statement(sql));
+    let parsed = statement(sql);
+    let mut copy = parsed.clone();
+    assert_eq!(copy, parsed);
+    copy.rename_alias("t", "x");
+    assert_ne!(copy, parsed);
+}
+
+#[test]
+fn constraints_keep_their_source_order() {
+    assert_eq!(
+        columns("CREATE TABLE t (a INT DEFAULT 0 NOT NULL CHECK (a >= 0));")[0].constraints,
+        vec![
+            Constraint::Default(Expression::Number(0)),
+            Constraint::NotNull,
+            Constraint::Check(Expression::BinaryOperation {
+                left_operand: Box::new(ident("a")),
+                operator: BinaryOperator::GreaterThanOrEqual,
+                right_operand: Box::new(Expression::Number(0)),
+            }),
+        ]
+    );
+    assert_eq!(
+        round_trip("CREATE TABLE t (a INT CHECK (a >= 0) NOT NULL DEFAULT 0);"),
+        "CREATE TABLE t (a INT CHECK (a >= 0) NOT NULL DEFAULT 0);"
+    );
+}
+
+fn insert_source(sql: &str, dialect: Dialect) -> InsertSource {
+    match dialect_statement(sql, dialect) {
+        Statement::Insert { source, .. } => source,
+        other => panic!("expected INSERT, got {:?}", other),
+    }
+}
+
+#[test]
+fn mysql_insert_set() {
+    assert_eq!(
+        insert_source("INSERT INTO t SET a = 1, b = 'x';", Dialect::MySql),
+        InsertSource::Assignments(vec![
+            ("a".to_string(), Expression::Number(1)),
+            ("b".to_string(), Expression::String("x".to_string())),
+        ])
+    );
+    assert_eq!(
+        insert_source("INSERT INTO t VALUES (1, 'x');", Dialect::MySql),
+        InsertSource::Values(vec![vec![Expression::Number(1), Expression::String("x".to_string())]])
+    );
+    statement_error("INSERT INTO t SET a = 1;");
+    assert!(sql_parser::parse_with_dialect("INSERT INTO t (a) SET a = 1;", Dialect::MySql).is_err());
+}
+
+#[test]
+fn non_reserved_keywords_as_names() {
+    match statement("SELECT action, t.no FROM t WHERE action = 1;") {
+        Statement::Select { columns, r#where, .. } => {
+            assert_eq!(
+                columns,
+                vec![ident("action"), Expression::QualifiedIdentifier { qualifier: "t".to_string(), name: "no".to_string() }]
+            );
+            assert!(matches!(r#where, Some(Expression::BinaryOperation { left_operand, .. }) if *left_operand == ident("action")));
+        }
+        other => panic!("expected SELECT, got {:?}", other),
+    }
+    let columns = columns("CREATE TABLE t (action INT, no INT REFERENCES u ON DELETE NO ACTION);");
+    assert_eq!(columns[0].column_name, "action");
+    assert_eq!(columns[1].column_name, "no");
+    assert_eq!(round_trip("SELECT a AS action FROM t ORDER BY no;"), "SELECT a AS action FROM t ORDER BY no;");
+}
@@ -0,0 +1,167 @@
+use sql_parser::parser::Parser;
+use sql_parser::statement::Statement;
+use sql_parser::token::{Keyword, Token};
+use sql_parser::tokenizer::Tokenizer;
+
+fn tokens(sql: &str) -> Vec<Token> {
+    Tokenizer::tokenize(sql).unwrap_or_else(|error| panic!("failed to tokenize {:?}: {}", sql, error))
+}
+
+fn tokens_with_terminator(sql: &str, terminator: &str) -> Vec<Token> {
+    Tokenizer::with_terminator(sql, terminator)
+        .into_tokens()
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|error| panic!("failed to tokenize {:?}: {}", sql, error))
+}
+
+#[test]
+fn custom_terminator_ends_a_statement() {
+    assert_eq!(
+        tokens_with_terminator("SELECT a FROM t //", "//"),
+        vec![
+            Token::Keyword(Keyword::Select),
+            Token::Identifier("a".to_string()),
+            Token::Keyword(Keyword::From),
+            Token::Identifier("t".to_string()),
+            Token::Semicolon,
+        ]
+    );
+    let statement = Parser::new(Tokenizer::with_terminator("SELECT a FROM t //", "//")).parse_only_statement(true);
+    assert!(matches!(statement, Ok(Statement::Select { .. })), "{:?}", statement);
+}
+
+#[test]
+fn custom_terminator_is_text_inside_strings_and_comments() {
+    assert_eq!(
+        tokens_with_terminator("SELECT '//' /* // */ -- //\n//", "//"),
+        vec![Token::Keyword(Keyword::Select), Token::String("//".to_string()), Token::Semicolon]
+    );
+}
+
+#[test]
+fn semicolon_is_rejected_under_custom_terminator() {
+    let error = Tokenizer::with_terminator("SELECT a FROM t;", "//")
+        .into_tokens()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_err();
+    assert_eq!(error.position, 15);
+    assert!(Parser::new(Tokenizer::with_terminator("SELECT a FROM t; //", "//")).parse_only_statement(false).is_err());
+}
+
+#[test]
+fn line_comments_are_skipped() {
+    assert_eq!(
+        tokens("SELECT a FROM t -- trailing note\nWHERE a > 1;"),
+        tokens("SELECT a FROM t WHERE a > 1;")
+    );
+    assert_eq!(tokens("-- only a comment"), vec![]);
+    assert_eq!(tokens("1 -- comment at end of input"), vec![Token::Number(1)]);
+}
+
+#[test]
+fn long_runs_of_comments_are_skipped() {
+    let sql = "-- note\n/* block */ ".repeat(100_000) + "1";
+    assert_eq!(tokens(&sql), vec![Token::Number(1)]);
+    let error = Tokenizer::tokenize("1 -- a\n  /* unterminated").unwrap_err();
+    assert_eq!(error.position, 9);
+}
+
+#[test]
+fn single_minus_is_still_an_operator() {
+    assert_eq!(
+        tokens("a - -b"),
+        vec![Token::Identifier("a".to_string()), Token::Minus, Token::Minus, Token::Identifier("b".to_string())]
+    );
+}
+
+#[test]
+fn doubled_quotes_in_strings() {
+    assert_eq!(tokens("'O''Brien'"), vec![Token::String("O'Brien".to_string())]);
+    assert_eq!(tokens("'''' ''"), vec![Token::String("'".to_string()), Token::String(String::new())]);
+}
+
+#[test]
+fn unterminated_string_error_position() {
+    let error = Tokenizer::tokenize("SELECT a FROM t WHERE b = 'abc").unwrap_err();
+    assert_eq!(error.position, 26);
+    assert!(error.message.starts_with("Unterminated"), "{}", error.message);
+    assert_eq!(error.to_string(), format!("{} at position 26", error.message));
+}
+
+#[test]
+fn less_than_family() {
+    assert_eq!(
+        tokens("<=> <= < >"),
+        vec![Token::NullSafeEqual, Token::LessThanOrEqual, Token::LessThan, Token::GreaterThan]
+    );
+}
+
+#[test]
+fn iterator_ends_after_a_single_eof() {
+    let tokens: Vec<Token> = Tokenizer::new("SELECT a").map(|token| token.unwrap().value).collect();
+    assert_eq!(
+        tokens,
+        vec![Token::Keyword(Keyword::Select), Token::Identifier("a".to_string()), Token::Eof]
+    );
+
+    let mut tokenizer = Tokenizer::new("");
+    assert_eq!(tokenizer.next().map(|token| token.unwrap().value), Some(Token::Eof));
+    assert!(tokenizer.next().is_none());
+    assert!(tokenizer.next().is_none());
+}
+
+#[test]
+fn into_tokens_leaves_out_eof() {
+    let tokens: Vec<Token> = Tokenizer::new("SELECT 1 + 2;").into_tokens().map(Result::unwrap).collect();
+    assert_eq!(
+        tokens,
+        vec![Token::Keyword(Keyword::Select), Token::Number(1), Token::Plus, Token::Number(2), Token::Semicolon]
+    );
+    assert_eq!(Tokenizer::new("").into_tokens().count(), 0);
+}
+
+#[test]
+fn into_tokens_reports_errors_and_terminates() {
+    let results: Vec<_> = Tokenizer::new("a @ b").into_tokens().collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[1].as_ref().unwrap_err().position, 2);
+    assert_eq!(results[2], Ok(Token::Identifier("b".to_string())));
+}
+
+#[test]
+fn quoted_identifiers() {
+    assert_eq!(
+        tokens("\"select\" `Group By` 'select'"),
+        vec![
+            Token::Identifier("select".to_string()),
+            Token::Identifier("Group By".to_string()),
+            Token::String("select".to_string()),
+        ]
+    );
+    assert_eq!(tokens("\"a\"\"b\""), vec![Token::Identifier("a\"b".to_string())]);
+}
+
+#[test]
+fn tokenize_collects_every_token() {
+    assert_eq!(
+        tokens("SELECT * FROM t;"),
+        vec![
+            Token::Keyword(Keyword::Select),
+            Token::Multiply,
+            Token::Keyword(Keyword::From),
+            Token::Identifier("t".to_string()),
+            Token::Semicolon,
+        ]
+    );
+    assert_eq!(Tokenizer::tokenize("SELECT 'open").unwrap_err().position, 7);
+}
+
+#[test]
+fn letters_glued_to_a_number() {
+    for (sql, literal, position) in [("LIMIT 10K", "10K", 6), ("a = 5MB", "5MB", 4)] {
+        let error = Tokenizer::tokenize(sql).unwrap_err();
+        assert_eq!(error.message, format!("invalid numeric literal '{}'", literal));
+        assert_eq!(error.position, position);
+    }
+    assert_eq!(tokens("10 K"), vec![Token::Number(10), Token::Identifier("K".to_string())]);
+}